@@ -0,0 +1,453 @@
+//! algorithms written against the traits in `graph::traits`, they run on every
+//! graph type implementing them.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    ops::Add,
+};
+
+use super::{
+    hash_map::DijkstraPair,
+    traits::{EdgeWeights, IntoNeighbors, IntoNodeIdentifiers},
+};
+
+pub struct Dfs<G: IntoNeighbors> {
+    graph: G,
+    stack: Vec<G::NodeId>,
+    visited: HashSet<G::NodeId>,
+}
+
+impl<G: IntoNeighbors> Iterator for Dfs<G> {
+    type Item = G::NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        for neib in self.graph.neighbors(node) {
+            if self.visited.insert(neib) {
+                self.stack.push(neib);
+            }
+        }
+        Some(node)
+    }
+}
+
+pub struct Bfs<G: IntoNeighbors> {
+    graph: G,
+    queue: VecDeque<G::NodeId>,
+    visited: HashSet<G::NodeId>,
+}
+
+impl<G: IntoNeighbors> Iterator for Bfs<G> {
+    type Item = G::NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        for neib in self.graph.neighbors(node) {
+            if self.visited.insert(neib) {
+                self.queue.push_back(neib);
+            }
+        }
+        Some(node)
+    }
+}
+
+/// nodes reachable from `start` in depth first order, starting with `start`.
+pub fn dfs<G: IntoNeighbors>(graph: G, start: G::NodeId) -> Dfs<G> {
+    Dfs {
+        graph,
+        stack: vec![start],
+        visited: HashSet::from([start]),
+    }
+}
+
+/// nodes reachable from `start` in breadth first order, starting with `start`.
+pub fn bfs<G: IntoNeighbors>(graph: G, start: G::NodeId) -> Bfs<G> {
+    Bfs {
+        graph,
+        queue: VecDeque::from([start]),
+        visited: HashSet::from([start]),
+    }
+}
+
+struct ShortestPaths<N, W> {
+    dist: HashMap<N, W>,
+    prev: HashMap<N, N>,
+}
+
+/// Dijkstra's algorithm, stops early once `dest` is settled.
+fn shortest_paths<G>(
+    graph: G,
+    start: G::NodeId,
+    dest: Option<G::NodeId>,
+) -> ShortestPaths<G::NodeId, G::Weight>
+where
+    G: IntoNeighbors + EdgeWeights,
+    G::Weight: Copy + Ord + Default + Add<Output = G::Weight>,
+{
+    let mut dist = HashMap::from([(start, G::Weight::default())]);
+    let mut prev = HashMap::new();
+    let mut done = HashSet::new();
+    let mut prio = BinaryHeap::from([Reverse(DijkstraPair(start, G::Weight::default()))]);
+
+    while let Some(Reverse(DijkstraPair(node, node_dist))) = prio.pop() {
+        if !done.insert(node) {
+            continue;
+        }
+        if dest == Some(node) {
+            break;
+        }
+        for neib in graph.neighbors(node) {
+            let Some(&weight) = graph.edge_weight(node, neib) else {
+                continue;
+            };
+            let new_dist = node_dist + weight;
+            if dist.get(&neib).is_none_or(|d| new_dist < *d) {
+                dist.insert(neib, new_dist);
+                prev.insert(neib, node);
+                prio.push(Reverse(DijkstraPair(neib, new_dist)));
+            }
+        }
+    }
+    ShortestPaths { dist, prev }
+}
+
+/// Dijkstra's algorithm, weights must not be negative.
+///
+/// # Returns
+/// distance from `start` to every node reachable from it.
+pub fn dijkstra<G>(graph: G, start: G::NodeId) -> HashMap<G::NodeId, G::Weight>
+where
+    G: IntoNeighbors + EdgeWeights,
+    G::Weight: Copy + Ord + Default + Add<Output = G::Weight>,
+{
+    shortest_paths(graph, start, None).dist
+}
+
+/// Dijkstra's algorithm, weights must not be negative.
+///
+/// # Returns
+/// nodes of a shortest path from `start` to `dest` and its length, None if `dest` is unreachable.
+pub fn dijkstra_shortest_path<G>(
+    graph: G,
+    start: G::NodeId,
+    dest: G::NodeId,
+) -> Option<(Vec<G::NodeId>, G::Weight)>
+where
+    G: IntoNeighbors + EdgeWeights,
+    G::Weight: Copy + Ord + Default + Add<Output = G::Weight>,
+{
+    let paths = shortest_paths(graph, start, Some(dest));
+    let dist = *paths.dist.get(&dest)?;
+    let mut path = vec![dest];
+    let mut node = dest;
+    while node != start {
+        node = paths.prev[&node];
+        path.push(node);
+    }
+    path.reverse();
+    Some((path, dist))
+}
+
+/// trail found by `hierholzer`.
+pub(crate) struct Trail {
+    pub(crate) nodes: Vec<usize>,
+    pub(crate) edges: Vec<usize>,
+}
+
+/// Hierholzer's algorithm with an explicit stack on the nodes `0..nodes_len`,
+/// every `(from, to)` of `edges` is used exactly once. an undirected edge can be
+/// walked from either end.
+///
+/// # Returns
+/// nodes and edge indices of an Eulerian trail, a circuit if `circuit`. a trail
+/// of a single node if there are no edges, None if there are no nodes either.
+pub(crate) fn hierholzer(
+    nodes_len: usize,
+    edges: &[(usize, usize)],
+    directed: bool,
+    circuit: bool,
+) -> Option<Trail> {
+    if edges.is_empty() {
+        return (nodes_len > 0).then(|| Trail {
+            nodes: vec![0],
+            edges: Vec::new(),
+        });
+    }
+    let mut adj: Vec<Vec<usize>> = vec![Vec::new(); nodes_len];
+    // out - in for directed graphs, degree for undirected ones
+    let mut balance: Vec<isize> = vec![0; nodes_len];
+    for (i, &(from, to)) in edges.iter().enumerate() {
+        adj[from].push(i);
+        if directed {
+            balance[from] += 1;
+            balance[to] -= 1;
+        } else {
+            if from != to {
+                adj[to].push(i);
+            }
+            balance[from] += 1;
+            balance[to] += 1;
+        }
+    }
+
+    let start = if directed {
+        let (mut starts, mut ends) = (0, 0);
+        for b in balance.iter() {
+            match b {
+                1 => starts += 1,
+                -1 => ends += 1,
+                0 => {}
+                _ => return None,
+            }
+        }
+        if starts != ends || starts > 1 || (circuit && starts > 0) {
+            return None;
+        }
+        balance.iter().position(|b| *b == 1)
+    } else {
+        let odd = balance.iter().filter(|d| *d % 2 == 1).count();
+        if odd > 2 || (circuit && odd > 0) {
+            return None;
+        }
+        balance.iter().position(|d| *d % 2 == 1)
+    }
+    .unwrap_or_else(|| adj.iter().position(|a| !a.is_empty()).unwrap());
+
+    let mut used = vec![false; edges.len()];
+    let mut next = vec![0; nodes_len];
+    let mut stack: Vec<(usize, Option<usize>)> = vec![(start, None)];
+    let mut trail = Trail {
+        nodes: Vec::with_capacity(edges.len() + 1),
+        edges: Vec::with_capacity(edges.len()),
+    };
+    while let Some(&(node, via)) = stack.last() {
+        while next[node] < adj[node].len() && used[adj[node][next[node]]] {
+            next[node] += 1;
+        }
+        match adj[node].get(next[node]) {
+            Some(&e) => {
+                used[e] = true;
+                let (from, to) = edges[e];
+                stack.push((if from == node { to } else { from }, Some(e)));
+            }
+            None => {
+                trail.nodes.push(node);
+                trail.edges.extend(via);
+                stack.pop();
+            }
+        }
+    }
+    // edges out of reach of `start`
+    if trail.edges.len() != edges.len() {
+        return None;
+    }
+    trail.nodes.reverse();
+    trail.edges.reverse();
+    Some(trail)
+}
+
+/// nodes of the graph and its edges as indices into them, an edge of an
+/// undirected graph is kept once. edges to nodes that aren't in
+/// `node_identifiers` are skipped.
+fn indexed_edges<G>(graph: G, directed: bool) -> (Vec<G::NodeId>, Vec<(usize, usize)>)
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let index: HashMap<G::NodeId, usize> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let mut edges = Vec::new();
+    for (i, node) in nodes.iter().enumerate() {
+        for neib in graph.neighbors(*node) {
+            match index.get(&neib) {
+                Some(&j) if directed || i <= j => edges.push((i, j)),
+                _ => {}
+            }
+        }
+    }
+    (nodes, edges)
+}
+
+/// nodes of the graph and the sorted indices of every node's neighbors with edge
+/// directions ignored. self loops are skipped.
+pub(super) fn undirected_adjacency<G>(graph: G) -> (Vec<G::NodeId>, Vec<Vec<usize>>)
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    let (nodes, edges) = indexed_edges(graph, true);
+    let mut adj: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    for (from, to) in edges.into_iter().filter(|(from, to)| from != to) {
+        adj[from].push(to);
+        adj[to].push(from);
+    }
+    for neibs in adj.iter_mut() {
+        neibs.sort_unstable();
+        neibs.dedup();
+    }
+    (nodes, adj)
+}
+
+fn eulerian<G>(graph: G, directed: bool, circuit: bool) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    let (nodes, edges) = indexed_edges(graph, directed);
+    let trail = hierholzer(nodes.len(), &edges, directed, circuit)?;
+    Some(trail.nodes.into_iter().map(|i| nodes[i]).collect())
+}
+
+/// Eulerian path of a directed graph by Hierholzer's algorithm, every edge is used exactly once.
+///
+/// # Returns
+/// nodes of the path, a single node if the graph has no edges.
+/// None if the graph is empty or has no Eulerian path.
+pub fn eulerian_path<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    eulerian(graph, true, false)
+}
+
+/// like `eulerian_path` but the path has to end where it starts.
+///
+/// # Returns
+/// nodes of the circuit, the first one is repeated at the end.
+pub fn eulerian_circuit<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    eulerian(graph, true, true)
+}
+
+/// Eulerian path of an undirected graph, `x` in the neighbors of `y` and `y` in
+/// the neighbors of `x` are the same edge.
+///
+/// # Returns
+/// nodes of the path, a single node if the graph has no edges.
+/// None if the graph is empty or has no Eulerian path.
+pub fn eulerian_path_undirected<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    eulerian(graph, false, false)
+}
+
+/// like `eulerian_path_undirected` but the path has to end where it starts.
+///
+/// # Returns
+/// nodes of the circuit, the first one is repeated at the end.
+pub fn eulerian_circuit_undirected<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    eulerian(graph, false, true)
+}
+
+pub fn has_eulerian_path<G>(graph: G) -> bool
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    eulerian_path(graph).is_some()
+}
+
+/// most nodes `hamiltonian_path`, `hamiltonian_cycle` and `hamiltonian_cycle_undirected` accept.
+pub const HAMILTONIAN_MAX_NODES: usize = 24;
+
+/// bitmask dynamic programming, `ends[mask]` has bit `v` set if a simple path
+/// visits exactly the nodes of `mask` and ends at `v`.
+fn hamiltonian<G>(graph: G, cycle: bool, directed: bool) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    let (nodes, edges) = indexed_edges(graph, true);
+    let n = nodes.len();
+    assert!(n <= HAMILTONIAN_MAX_NODES);
+    // an undirected cycle of two nodes would go back over its only edge
+    if n == 0 || (cycle && !directed && n == 2) {
+        return None;
+    }
+    let mut outs = vec![0u32; n];
+    for (from, to) in edges {
+        outs[from] |= 1 << to;
+    }
+
+    let full = (1usize << n) - 1;
+    let mut ends = vec![0u32; full + 1];
+    if cycle {
+        // a cycle can start anywhere, fix it at node 0
+        ends[1] = 1;
+    } else {
+        for v in 0..n {
+            ends[1 << v] = 1 << v;
+        }
+    }
+    for mask in 1..full {
+        let mut last = ends[mask];
+        while last != 0 {
+            let v = last.trailing_zeros() as usize;
+            last &= last - 1;
+            let mut next = outs[v] & !(mask as u32);
+            while next != 0 {
+                let w = next.trailing_zeros() as usize;
+                next &= next - 1;
+                ends[mask | 1 << w] |= 1 << w;
+            }
+        }
+    }
+
+    let mut end = (0..n).find(|v| ends[full] >> v & 1 == 1 && (!cycle || outs[*v] & 1 == 1))?;
+    // walk back through the masks
+    let mut path = vec![end];
+    let mut mask = full;
+    while mask.count_ones() > 1 {
+        let prev_mask = mask & !(1 << end);
+        end = (0..n)
+            .find(|u| ends[prev_mask] >> u & 1 == 1 && outs[*u] >> end & 1 == 1)
+            .unwrap();
+        path.push(end);
+        mask = prev_mask;
+    }
+    path.reverse();
+    Some(path.into_iter().map(|i| nodes[i]).collect())
+}
+
+/// simple path visiting every node once, O(2^n * n^2) so meant for small graphs.
+///
+/// # Returns
+/// None if there's no such path or the graph is empty.
+///
+/// # Panics
+/// if the graph has more than `HAMILTONIAN_MAX_NODES` nodes
+pub fn hamiltonian_path<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    hamiltonian(graph, false, true)
+}
+
+/// cycle visiting every node once, O(2^n * n^2) so meant for small graphs.
+///
+/// # Returns
+/// nodes of the cycle, the last one has an edge to the first.
+/// None if there's no such cycle or the graph is empty.
+///
+/// # Panics
+/// if the graph has more than `HAMILTONIAN_MAX_NODES` nodes
+pub fn hamiltonian_cycle<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    hamiltonian(graph, true, true)
+}
+
+/// like `hamiltonian_cycle` for an undirected graph, `x` in the neighbors of `y`
+/// and `y` in the neighbors of `x` are the same edge so two nodes never form a cycle.
+///
+/// # Panics
+/// if the graph has more than `HAMILTONIAN_MAX_NODES` nodes
+pub fn hamiltonian_cycle_undirected<G>(graph: G) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    hamiltonian(graph, true, false)
+}
@@ -0,0 +1,518 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    slice,
+};
+
+use super::hash_map::{self, EdgeErr};
+
+/// handle of a node, stays valid until the node is removed. a removed node's
+/// slot is reused with a new generation so old handles never alias new nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeIndex {
+    index: usize,
+    generation: u32,
+}
+
+/// handle of an edge, same rules as `NodeIndex`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EdgeIndex {
+    index: usize,
+    generation: u32,
+}
+
+impl NodeIndex {
+    /// position in the graph's node storage, always less than `Graph::node_bound`.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl EdgeIndex {
+    /// position in the graph's edge storage, always less than `Graph::edge_bound`.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Slot<V> {
+    generation: u32,
+    val: Option<V>,
+}
+
+#[derive(Debug, Clone)]
+struct Arena<V> {
+    slots: Vec<Slot<V>>,
+    free: Vec<usize>,
+    len: usize,
+}
+
+impl<V> Arena<V> {
+    const fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+        }
+    }
+
+    fn insert(&mut self, val: V) -> (usize, u32) {
+        self.len += 1;
+        match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index];
+                slot.val = Some(val);
+                (index, slot.generation)
+            }
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    val: Some(val),
+                });
+                (self.slots.len() - 1, 0)
+            }
+        }
+    }
+
+    fn remove(&mut self, index: usize, generation: u32) -> Option<V> {
+        let slot = self.slots.get_mut(index)?;
+        if slot.generation != generation {
+            return None;
+        }
+        let val = slot.val.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(index);
+        self.len -= 1;
+        Some(val)
+    }
+
+    fn get(&self, index: usize, generation: u32) -> Option<&V> {
+        let slot = self.slots.get(index)?;
+        if slot.generation != generation {
+            return None;
+        }
+        slot.val.as_ref()
+    }
+
+    fn get_mut(&mut self, index: usize, generation: u32) -> Option<&mut V> {
+        let slot = self.slots.get_mut(index)?;
+        if slot.generation != generation {
+            return None;
+        }
+        slot.val.as_mut()
+    }
+
+    fn clear(&mut self) {
+        for (index, slot) in self.slots.iter_mut().enumerate() {
+            if slot.val.take().is_some() {
+                slot.generation = slot.generation.wrapping_add(1);
+                self.free.push(index);
+            }
+        }
+        self.len = 0;
+    }
+}
+
+#[derive(Debug, Clone)]
+struct NodeData<T> {
+    val: T,
+    outs: Vec<EdgeIndex>,
+    ins: Vec<EdgeIndex>,
+}
+
+#[derive(Debug, Clone)]
+struct EdgeData<W> {
+    from: NodeIndex,
+    to: NodeIndex,
+    weight: W,
+}
+
+/// directed graph stored in vectors, nodes and edges are addressed by
+/// index handles instead of hashed keys.
+#[derive(Debug, Clone)]
+pub struct Graph<T, W> {
+    nodes: Arena<NodeData<T>>,
+    edges: Arena<EdgeData<W>>,
+}
+
+impl<T, W> Graph<T, W> {
+    pub const fn new() -> Self {
+        Self {
+            nodes: Arena::new(),
+            edges: Arena::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.len == 0
+    }
+
+    pub fn nodes_len(&self) -> usize {
+        self.nodes.len
+    }
+
+    pub fn edges_len(&self) -> usize {
+        self.edges.len
+    }
+
+    /// every `NodeIndex::index` of this graph is less than this.
+    pub fn node_bound(&self) -> usize {
+        self.nodes.slots.len()
+    }
+
+    /// every `EdgeIndex::index` of this graph is less than this.
+    pub fn edge_bound(&self) -> usize {
+        self.edges.slots.len()
+    }
+
+    pub fn add_node(&mut self, val: T) -> NodeIndex {
+        let (index, generation) = self.nodes.insert(NodeData {
+            val,
+            outs: Vec::new(),
+            ins: Vec::new(),
+        });
+        NodeIndex { index, generation }
+    }
+
+    /// removes the node and every edge touching it.
+    pub fn remove_node(&mut self, node: NodeIndex) -> Option<T> {
+        let data = self.nodes.remove(node.index, node.generation)?;
+        for edge in data.outs.into_iter().chain(data.ins) {
+            self.remove_edge(edge);
+        }
+        Some(data.val)
+    }
+
+    pub fn contains_node(&self, node: NodeIndex) -> bool {
+        self.nodes.get(node.index, node.generation).is_some()
+    }
+
+    pub fn node(&self, node: NodeIndex) -> Option<&T> {
+        self.nodes.get(node.index, node.generation).map(|n| &n.val)
+    }
+
+    pub fn node_mut(&mut self, node: NodeIndex) -> Option<&mut T> {
+        self.nodes
+            .get_mut(node.index, node.generation)
+            .map(|n| &mut n.val)
+    }
+
+    /// # Error
+    /// if graph doesn't contain `to` returns `Err(EdgeErr::ToNone)`.
+    /// if graph doesn't contain `from` returns `Err(EdgeErr::FromNone)`.
+    pub fn add_edge(
+        &mut self,
+        from: NodeIndex,
+        to: NodeIndex,
+        weight: W,
+    ) -> Result<EdgeIndex, EdgeErr> {
+        if !self.contains_node(to) {
+            return Err(EdgeErr::ToNone);
+        }
+        if !self.contains_node(from) {
+            return Err(EdgeErr::FromNone);
+        }
+        let (index, generation) = self.edges.insert(EdgeData { from, to, weight });
+        let edge = EdgeIndex { index, generation };
+        self.nodes.slots[from.index]
+            .val
+            .as_mut()
+            .unwrap()
+            .outs
+            .push(edge);
+        self.nodes.slots[to.index]
+            .val
+            .as_mut()
+            .unwrap()
+            .ins
+            .push(edge);
+        Ok(edge)
+    }
+
+    pub fn remove_edge(&mut self, edge: EdgeIndex) -> Option<W> {
+        let data = self.edges.remove(edge.index, edge.generation)?;
+        if let Some(from) = self.nodes.get_mut(data.from.index, data.from.generation) {
+            from.outs.retain(|e| *e != edge);
+        }
+        if let Some(to) = self.nodes.get_mut(data.to.index, data.to.generation) {
+            to.ins.retain(|e| *e != edge);
+        }
+        Some(data.weight)
+    }
+
+    /// # Returns
+    /// `(from, to)` of the edge.
+    pub fn edge_endpoints(&self, edge: EdgeIndex) -> Option<(NodeIndex, NodeIndex)> {
+        self.edges
+            .get(edge.index, edge.generation)
+            .map(|e| (e.from, e.to))
+    }
+
+    pub fn get_weight(&self, edge: EdgeIndex) -> Option<&W> {
+        self.edges
+            .get(edge.index, edge.generation)
+            .map(|e| &e.weight)
+    }
+
+    pub fn get_weight_mut(&mut self, edge: EdgeIndex) -> Option<&mut W> {
+        self.edges
+            .get_mut(edge.index, edge.generation)
+            .map(|e| &mut e.weight)
+    }
+
+    /// # Returns
+    /// first edge from `from` to `to`.
+    pub fn find_edge(&self, from: NodeIndex, to: NodeIndex) -> Option<EdgeIndex> {
+        let data = self.nodes.get(from.index, from.generation)?;
+        data.outs
+            .iter()
+            .find(|e| self.edges.slots[e.index].val.as_ref().unwrap().to == to)
+            .copied()
+    }
+
+    /// # Returns
+    /// `(to, weight)` of every edge leaving `node`, empty if graph doesn't contain `node`.
+    pub fn neighbors(&self, node: NodeIndex) -> Neighbors<'_, T, W> {
+        let edges = match self.nodes.get(node.index, node.generation) {
+            Some(data) => data.outs.iter(),
+            None => [].iter(),
+        };
+        Neighbors {
+            graph: self,
+            edges,
+            incoming: false,
+        }
+    }
+
+    /// # Returns
+    /// `(from, weight)` of every edge entering `node`, empty if graph doesn't contain `node`.
+    pub fn in_neighbors(&self, node: NodeIndex) -> Neighbors<'_, T, W> {
+        let edges = match self.nodes.get(node.index, node.generation) {
+            Some(data) => data.ins.iter(),
+            None => [].iter(),
+        };
+        Neighbors {
+            graph: self,
+            edges,
+            incoming: true,
+        }
+    }
+
+    pub fn node_indices(&self) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.nodes
+            .slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| slot.val.is_some())
+            .map(|(index, slot)| NodeIndex {
+                index,
+                generation: slot.generation,
+            })
+    }
+
+    pub fn edge_indices(&self) -> impl Iterator<Item = EdgeIndex> + '_ {
+        self.edges
+            .slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| slot.val.is_some())
+            .map(|(index, slot)| EdgeIndex {
+                index,
+                generation: slot.generation,
+            })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (NodeIndex, &T)> + '_ {
+        self.node_indices().map(|n| (n, self.node(n).unwrap()))
+    }
+
+    /// every handle given out before stays invalid.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.edges.clear();
+    }
+
+    /// # Panics
+    /// if graph doesn't contain `start`.
+    pub fn dfs_iter(&self, start: NodeIndex) -> DfsIter<'_, T, W> {
+        assert!(self.contains_node(start));
+        let mut visited = vec![false; self.node_bound()];
+        visited[start.index] = true;
+        DfsIter {
+            graph: self,
+            stack: vec![start],
+            visited,
+        }
+    }
+
+    /// # Panics
+    /// if graph doesn't contain `start`.
+    pub fn bfs_iter(&self, start: NodeIndex) -> BfsIter<'_, T, W> {
+        assert!(self.contains_node(start));
+        let mut visited = vec![false; self.node_bound()];
+        visited[start.index] = true;
+        BfsIter {
+            graph: self,
+            queue: VecDeque::from([start]),
+            visited,
+        }
+    }
+}
+
+impl<T> Graph<T, usize> {
+    /// # Returns
+    /// nodes of a shortest path from `start` to `dest` and its length.
+    pub fn dijkstra_shortest_path(
+        &self,
+        start: NodeIndex,
+        dest: NodeIndex,
+    ) -> Option<(Vec<NodeIndex>, usize)> {
+        if !self.contains_node(start) || !self.contains_node(dest) {
+            return None;
+        }
+        let mut dist: Vec<Option<usize>> = vec![None; self.node_bound()];
+        let mut prev: Vec<Option<NodeIndex>> = vec![None; self.node_bound()];
+        let mut prio = BinaryHeap::from([Reverse((0, start))]);
+        dist[start.index] = Some(0);
+
+        while let Some(Reverse((node_dist, node))) = prio.pop() {
+            if node == dest {
+                let mut path = vec![dest];
+                let mut node = dest;
+                while let Some(p) = prev[node.index] {
+                    path.push(p);
+                    node = p;
+                }
+                path.reverse();
+                return Some((path, node_dist));
+            }
+            if dist[node.index].is_some_and(|d| node_dist > d) {
+                continue;
+            }
+            for (neib, weight) in self.neighbors(node) {
+                let new_dist = node_dist + weight;
+                if dist[neib.index].is_none_or(|d| new_dist < d) {
+                    dist[neib.index] = Some(new_dist);
+                    prev[neib.index] = Some(node);
+                    prio.push(Reverse((new_dist, neib)));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl<T, W> Default for Graph<T, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Neighbors<'a, T, W> {
+    graph: &'a Graph<T, W>,
+    edges: slice::Iter<'a, EdgeIndex>,
+    incoming: bool,
+}
+
+impl<'a, T, W> Iterator for Neighbors<'a, T, W> {
+    type Item = (NodeIndex, &'a W);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let edge = self.edges.next()?;
+        let data = self.graph.edges.slots[edge.index].val.as_ref().unwrap();
+        let neib = if self.incoming { data.from } else { data.to };
+        Some((neib, &data.weight))
+    }
+}
+
+pub struct DfsIter<'a, T, W> {
+    graph: &'a Graph<T, W>,
+    stack: Vec<NodeIndex>,
+    visited: Vec<bool>,
+}
+
+impl<'a, T, W> Iterator for DfsIter<'a, T, W> {
+    type Item = NodeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        for (neib, _) in self.graph.neighbors(node) {
+            if !self.visited[neib.index] {
+                self.visited[neib.index] = true;
+                self.stack.push(neib);
+            }
+        }
+        Some(node)
+    }
+}
+
+pub struct BfsIter<'a, T, W> {
+    graph: &'a Graph<T, W>,
+    queue: VecDeque<NodeIndex>,
+    visited: Vec<bool>,
+}
+
+impl<'a, T, W> Iterator for BfsIter<'a, T, W> {
+    type Item = NodeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        for (neib, _) in self.graph.neighbors(node) {
+            if !self.visited[neib.index] {
+                self.visited[neib.index] = true;
+                self.queue.push_back(neib);
+            }
+        }
+        Some(node)
+    }
+}
+
+impl<K, T, W> From<&hash_map::Graph<K, T, W>> for Graph<(K, T), W>
+where
+    K: Hash + Eq + Clone,
+    T: Clone,
+    W: Clone,
+{
+    /// every node keeps its key next to its value.
+    fn from(value: &hash_map::Graph<K, T, W>) -> Self {
+        let mut graph = Graph::new();
+        let mut index: HashMap<&K, NodeIndex> = HashMap::with_capacity(value.nodes_len());
+        for (key, node) in value.iter() {
+            index.insert(key, graph.add_node((key.clone(), node.val().clone())));
+        }
+        for edge in value.edges() {
+            if let Some(&to) = index.get(edge.to) {
+                graph
+                    .add_edge(index[edge.from], to, edge.weight.clone())
+                    .unwrap();
+            }
+        }
+        graph
+    }
+}
+
+impl<K, T, W> From<&Graph<(K, T), W>> for hash_map::Graph<K, T, W>
+where
+    K: Hash + Eq + Clone,
+    T: Clone,
+    W: Clone,
+{
+    /// parallel edges collapse into one of them.
+    fn from(value: &Graph<(K, T), W>) -> Self {
+        let mut graph = hash_map::Graph::new();
+        for (_, (key, val)) in value.iter() {
+            graph.insert_node(key.clone(), val.clone());
+        }
+        for edge in value.edge_indices() {
+            let data = value.edges.slots[edge.index].val.as_ref().unwrap();
+            let (from, _) = value.node(data.from).unwrap();
+            let (to, _) = value.node(data.to).unwrap();
+            graph
+                .insert_edge(from.clone(), to.clone(), data.weight.clone())
+                .unwrap();
+        }
+        graph
+    }
+}
@@ -0,0 +1,85 @@
+use std::ops::{Add, Sub};
+
+use crate::matrix::vec::Matrix;
+
+/// Hungarian algorithm with potentials in O(rows^2 * cols), assigns every row
+/// to a different column with the minimum total cost. column potentials are
+/// kept negated so every intermediate value stays non-negative for
+/// non-negative costs, unsigned `W` works too.
+///
+/// # Returns
+/// column assigned to every row and the total cost.
+///
+/// # Panics
+/// if `costs` has more rows than columns
+pub fn hungarian<W>(costs: &Matrix<W>) -> (Vec<usize>, W)
+where
+    W: Copy + Ord + Default + Add<Output = W> + Sub<Output = W>,
+{
+    let (n, m) = (costs.rows_len(), costs.cols_len());
+    assert!(n <= m);
+    let cost = |i: usize, j: usize| *costs.get(i - 1, j - 1).unwrap();
+
+    // row and negated column potentials, index 0 is a sentinel column
+    let mut u = vec![W::default(); n + 1];
+    let mut v = vec![W::default(); m + 1];
+    // row assigned to every column, 0 if none
+    let mut row_of = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+
+    for i in 1..=n {
+        row_of[0] = i;
+        let mut j0 = 0;
+        let mut min_slack: Vec<Option<W>> = vec![None; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = row_of[j0];
+            let mut delta: Option<W> = None;
+            let mut j1 = 0;
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let slack = cost(i0, j) + v[j] - u[i0];
+                if min_slack[j].is_none_or(|s| slack < s) {
+                    min_slack[j] = Some(slack);
+                    way[j] = j0;
+                }
+                if delta.is_none_or(|d| min_slack[j].unwrap() < d) {
+                    delta = min_slack[j];
+                    j1 = j;
+                }
+            }
+            let delta = delta.unwrap();
+            for j in 0..=m {
+                if used[j] {
+                    u[row_of[j]] = u[row_of[j]] + delta;
+                    v[j] = v[j] + delta;
+                } else {
+                    min_slack[j] = min_slack[j].map(|s| s - delta);
+                }
+            }
+            j0 = j1;
+            if row_of[j0] == 0 {
+                break;
+            }
+        }
+        // flip the alternating path back to the sentinel
+        while j0 != 0 {
+            let j1 = way[j0];
+            row_of[j0] = row_of[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut assignment = vec![0; n];
+    let mut total = W::default();
+    for j in 1..=m {
+        if row_of[j] != 0 {
+            assignment[row_of[j] - 1] = j - 1;
+            total = total + cost(row_of[j], j);
+        }
+    }
+    (assignment, total)
+}
@@ -0,0 +1,164 @@
+//! cliques and independent sets of a graph seen as undirected, written against the
+//! traits in `graph::traits`. edge directions are ignored and self loops skipped.
+
+use std::cmp::Reverse;
+
+use super::{
+    algo::undirected_adjacency,
+    traits::{IntoNeighbors, IntoNodeIdentifiers},
+};
+
+fn connected(adj: &[Vec<usize>], u: usize, v: usize) -> bool {
+    adj[u].binary_search(&v).is_ok()
+}
+
+/// Bron–Kerbosch with pivoting, every clique that isn't part of a larger one.
+/// a graph can have exponentially many of them.
+///
+/// # Returns
+/// nodes of every maximal clique in the order of `node_identifiers`, an isolated
+/// node is a clique of its own.
+/// empty if the graph is empty.
+pub fn maximal_cliques<G>(graph: G) -> Vec<Vec<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    let (nodes, adj) = undirected_adjacency(graph);
+    if nodes.is_empty() {
+        return Vec::new();
+    }
+    let mut cliques = Vec::new();
+    let candidates = (0..nodes.len()).collect();
+    bron_kerbosch(&adj, &mut Vec::new(), candidates, Vec::new(), &mut cliques);
+    cliques
+        .into_iter()
+        .map(|mut clique| {
+            clique.sort_unstable();
+            clique.into_iter().map(|i| nodes[i]).collect()
+        })
+        .collect()
+}
+
+/// every maximal clique containing `clique` and nodes of `candidates` but none of
+/// `excluded`, which were tried already.
+fn bron_kerbosch(
+    adj: &[Vec<usize>],
+    clique: &mut Vec<usize>,
+    mut candidates: Vec<usize>,
+    mut excluded: Vec<usize>,
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() {
+            cliques.push(clique.clone());
+        }
+        return;
+    }
+    // every maximal clique here contains the pivot or one of its non neighbors
+    let pivot = candidates
+        .iter()
+        .chain(excluded.iter())
+        .copied()
+        .max_by_key(|&u| candidates.iter().filter(|&&v| connected(adj, u, v)).count())
+        .unwrap();
+    let branches: Vec<usize> = candidates
+        .iter()
+        .copied()
+        .filter(|&v| !connected(adj, pivot, v))
+        .collect();
+    for v in branches {
+        clique.push(v);
+        bron_kerbosch(
+            adj,
+            clique,
+            candidates
+                .iter()
+                .copied()
+                .filter(|&u| connected(adj, v, u))
+                .collect(),
+            excluded
+                .iter()
+                .copied()
+                .filter(|&u| connected(adj, v, u))
+                .collect(),
+            cliques,
+        );
+        clique.pop();
+        candidates.retain(|&u| u != v);
+        excluded.push(v);
+    }
+}
+
+/// largest set of pairwise adjacent nodes by branch and bound, exponential in the
+/// worst case so meant for small or sparse graphs.
+///
+/// # Returns
+/// empty if the graph is empty.
+pub fn maximum_clique<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    let (nodes, adj) = undirected_adjacency(graph);
+    largest_clique(&adj).into_iter().map(|i| nodes[i]).collect()
+}
+
+/// largest set of nodes with no edge between any two of them, the maximum clique
+/// of the complement graph. exponential in the worst case so meant for small graphs.
+///
+/// # Returns
+/// empty if the graph is empty.
+pub fn maximum_independent_set<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    let (nodes, adj) = undirected_adjacency(graph);
+    let n = nodes.len();
+    let complement: Vec<Vec<usize>> = (0..n)
+        .map(|u| {
+            (0..n)
+                .filter(|&v| v != u && !connected(&adj, u, v))
+                .collect()
+        })
+        .collect();
+    largest_clique(&complement)
+        .into_iter()
+        .map(|i| nodes[i])
+        .collect()
+}
+
+/// indices of a maximum clique of the graph with sorted adjacency lists `adj`.
+pub(super) fn largest_clique(adj: &[Vec<usize>]) -> Vec<usize> {
+    // nodes of high degree first so a large clique is found early
+    let mut order: Vec<usize> = (0..adj.len()).collect();
+    order.sort_by_key(|&v| Reverse(adj[v].len()));
+    let mut best = Vec::new();
+    expand(adj, &mut Vec::new(), &order, &mut best);
+    best
+}
+
+/// extends `clique` by nodes of `candidates`, which are adjacent to all of it.
+/// a node is only followed by the candidates after it so every clique is tried once.
+fn expand(
+    adj: &[Vec<usize>],
+    clique: &mut Vec<usize>,
+    candidates: &[usize],
+    best: &mut Vec<usize>,
+) {
+    if clique.len() > best.len() {
+        *best = clique.clone();
+    }
+    for (i, &v) in candidates.iter().enumerate() {
+        // even every candidate left can't make it larger than `best`
+        if clique.len() + candidates.len() - i <= best.len() {
+            return;
+        }
+        let next: Vec<usize> = candidates[i + 1..]
+            .iter()
+            .copied()
+            .filter(|&u| connected(adj, v, u))
+            .collect();
+        clique.push(v);
+        expand(adj, clique, &next, best);
+        clique.pop();
+    }
+}
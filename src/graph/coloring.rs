@@ -0,0 +1,171 @@
+//! vertex coloring of a graph seen as undirected, written against the traits in
+//! `graph::traits`. adjacent nodes get different colors, colors are `0..k`. edge
+//! directions are ignored and self loops skipped.
+
+use std::{cmp::Reverse, collections::HashMap};
+
+use super::{
+    algo::undirected_adjacency,
+    clique::largest_clique,
+    traits::{IntoNeighbors, IntoNodeIdentifiers},
+};
+
+/// order `greedy_coloring` colors the nodes in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// order of `node_identifiers`.
+    Natural,
+    /// nodes of higher degree first, Welsh–Powell.
+    LargestFirst,
+    /// next is the node with the most different colors among its neighbors, ties
+    /// broken by degree. optimal for bipartite graphs.
+    DSatur,
+}
+
+/// colors the nodes one by one with the smallest color none of their neighbors has,
+/// fast but not optimal in general.
+///
+/// # Returns
+/// color of every node.
+pub fn greedy_coloring<G>(graph: G, strategy: Strategy) -> HashMap<G::NodeId, usize>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    let (nodes, adj) = undirected_adjacency(graph);
+    let colors = match strategy {
+        Strategy::Natural => greedy(&adj, 0..adj.len()),
+        Strategy::LargestFirst => {
+            let mut order: Vec<usize> = (0..adj.len()).collect();
+            order.sort_by_key(|&v| Reverse(adj[v].len()));
+            greedy(&adj, order)
+        }
+        Strategy::DSatur => dsatur(&adj),
+    };
+    nodes.into_iter().zip(colors).collect()
+}
+
+/// coloring with the fewest colors by DSatur based branch and bound, exponential
+/// in the worst case so meant for small graphs.
+///
+/// # Returns
+/// color of every node.
+pub fn optimal_coloring<G>(graph: G) -> HashMap<G::NodeId, usize>
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    let (nodes, adj) = undirected_adjacency(graph);
+    nodes.into_iter().zip(exact(&adj)).collect()
+}
+
+/// fewest colors a coloring of the graph needs, see `optimal_coloring`.
+///
+/// # Returns
+/// 0 if the graph is empty.
+pub fn chromatic_number<G>(graph: G) -> usize
+where
+    G: IntoNodeIdentifiers + IntoNeighbors,
+{
+    let (_, adj) = undirected_adjacency(graph);
+    exact(&adj).into_iter().max().map_or(0, |c| c + 1)
+}
+
+const NONE: usize = usize::MAX;
+
+/// smallest color none of the neighbors of `v` has.
+fn smallest_free(adj: &[Vec<usize>], colors: &[usize], v: usize) -> usize {
+    let mut used = vec![false; adj[v].len() + 1];
+    for &u in adj[v].iter() {
+        if colors[u] < used.len() {
+            used[colors[u]] = true;
+        }
+    }
+    used.iter().position(|u| !u).unwrap()
+}
+
+fn greedy(adj: &[Vec<usize>], order: impl IntoIterator<Item = usize>) -> Vec<usize> {
+    let mut colors = vec![NONE; adj.len()];
+    for v in order {
+        colors[v] = smallest_free(adj, &colors, v);
+    }
+    colors
+}
+
+/// number of different colors among the neighbors of `v`.
+fn saturation(adj: &[Vec<usize>], colors: &[usize], v: usize) -> usize {
+    let mut seen: Vec<usize> = adj[v]
+        .iter()
+        .map(|&u| colors[u])
+        .filter(|&c| c != NONE)
+        .collect();
+    seen.sort_unstable();
+    seen.dedup();
+    seen.len()
+}
+
+/// uncolored node with the highest saturation, ties broken by degree then index.
+fn most_saturated(adj: &[Vec<usize>], colors: &[usize]) -> Option<usize> {
+    (0..adj.len())
+        .filter(|&v| colors[v] == NONE)
+        .max_by_key(|&v| (saturation(adj, colors, v), adj[v].len(), Reverse(v)))
+}
+
+fn dsatur(adj: &[Vec<usize>]) -> Vec<usize> {
+    let mut colors = vec![NONE; adj.len()];
+    while let Some(v) = most_saturated(adj, &colors) {
+        colors[v] = smallest_free(adj, &colors, v);
+    }
+    colors
+}
+
+struct Search<'a> {
+    adj: &'a [Vec<usize>],
+    colors: Vec<usize>,
+    best: Vec<usize>,
+    best_len: usize,
+    /// size of a maximum clique, no coloring has fewer colors.
+    lower: usize,
+}
+
+impl Search<'_> {
+    /// colors the uncolored nodes, `used` colors are taken so far.
+    ///
+    /// # Returns
+    /// true once a coloring with `lower` colors is found.
+    fn color(&mut self, used: usize) -> bool {
+        let Some(v) = most_saturated(self.adj, &self.colors) else {
+            // only colorings with fewer colors than `best` get here
+            self.best = self.colors.clone();
+            self.best_len = used;
+            return used == self.lower;
+        };
+        // one new color is tried since the unused ones are interchangeable
+        let mut c = 0;
+        while c <= used && c + 1 < self.best_len {
+            if self.adj[v].iter().all(|&u| self.colors[u] != c) {
+                self.colors[v] = c;
+                let done = self.color(used.max(c + 1));
+                self.colors[v] = NONE;
+                if done {
+                    return true;
+                }
+            }
+            c += 1;
+        }
+        false
+    }
+}
+
+fn exact(adj: &[Vec<usize>]) -> Vec<usize> {
+    let best = dsatur(adj);
+    let mut search = Search {
+        adj,
+        colors: vec![NONE; adj.len()],
+        best_len: best.iter().max().map_or(0, |c| c + 1),
+        best,
+        lower: largest_clique(adj).len(),
+    };
+    if search.best_len > search.lower {
+        search.color(0);
+    }
+    search.best
+}
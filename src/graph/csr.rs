@@ -0,0 +1,319 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::{BuildHasher, Hash},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use rayon::prelude::*;
+
+use super::hash_map;
+
+/// read-only directed graph in compressed sparse row form. the edges of node
+/// `u` are `targets[offsets[u]..offsets[u + 1]]` with the matching `weights`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsrGraph<W> {
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+    weights: Vec<W>,
+}
+
+impl<W> CsrGraph<W> {
+    /// builds the graph of nodes `0..nodes_len`, edges of a node keep their order in `edges`.
+    ///
+    /// # Panics
+    /// if an edge has an end >= nodes_len
+    pub fn from_edges(nodes_len: usize, edges: Vec<(usize, usize, W)>) -> Self {
+        let mut offsets = vec![0; nodes_len + 1];
+        for (from, to, _) in edges.iter() {
+            assert!(*from < nodes_len && *to < nodes_len);
+            offsets[from + 1] += 1;
+        }
+        for i in 0..nodes_len {
+            offsets[i + 1] += offsets[i];
+        }
+
+        let mut next = offsets.clone();
+        let mut slots: Vec<Option<(usize, W)>> = Vec::with_capacity(edges.len());
+        slots.resize_with(edges.len(), || None);
+        for (from, to, weight) in edges {
+            slots[next[from]] = Some((to, weight));
+            next[from] += 1;
+        }
+        let (targets, weights) = slots.into_iter().map(|i| i.unwrap()).unzip();
+
+        Self {
+            offsets,
+            targets,
+            weights,
+        }
+    }
+
+    /// # Returns
+    /// the graph and the key of every node index, nodes and edges keep the
+    /// iteration order of `graph`.
+    pub fn from_graph<K, T, S>(graph: &hash_map::Graph<K, T, W, S>) -> (Self, Vec<K>)
+    where
+        K: Hash + Eq + Clone,
+        W: Clone,
+        S: BuildHasher + Default,
+    {
+        let keys: Vec<&K> = graph.iter().map(|(k, _)| k).collect();
+        let index: HashMap<&K, usize> = keys.iter().enumerate().map(|(i, k)| (*k, i)).collect();
+        let mut edges = Vec::with_capacity(graph.edges_len());
+        for (i, key) in keys.iter().enumerate() {
+            for (neib, weight) in graph.out_neighbors(key).unwrap() {
+                if let Some(&j) = index.get(neib) {
+                    edges.push((i, j, weight.clone()));
+                }
+            }
+        }
+        let keys = keys.into_iter().cloned().collect();
+        (Self::from_edges(index.len(), edges), keys)
+    }
+
+    pub fn nodes_len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub fn edges_len(&self) -> usize {
+        self.targets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes_len() == 0
+    }
+
+    /// # Panics
+    /// if node >= nodes_len
+    pub fn neighbors(&self, node: usize) -> &[usize] {
+        &self.targets[self.offsets[node]..self.offsets[node + 1]]
+    }
+
+    /// weights of the edges to `neighbors(node)`, in the same order.
+    ///
+    /// # Panics
+    /// if node >= nodes_len
+    pub fn weights(&self, node: usize) -> &[W] {
+        &self.weights[self.offsets[node]..self.offsets[node + 1]]
+    }
+
+    /// # Panics
+    /// if node >= nodes_len
+    pub fn degree(&self, node: usize) -> usize {
+        self.offsets[node + 1] - self.offsets[node]
+    }
+
+    /// graph with every edge reversed.
+    pub fn transpose(&self) -> Self
+    where
+        W: Clone,
+    {
+        let mut edges = Vec::with_capacity(self.edges_len());
+        for from in 0..self.nodes_len() {
+            for (to, weight) in self.neighbors(from).iter().zip(self.weights(from)) {
+                edges.push((*to, from, weight.clone()));
+            }
+        }
+        Self::from_edges(self.nodes_len(), edges)
+    }
+
+    /// # Panics
+    /// if start >= nodes_len
+    pub fn dfs_iter(&self, start: usize) -> DfsIter<'_, W> {
+        let mut visited = vec![false; self.nodes_len()];
+        visited[start] = true;
+        DfsIter {
+            graph: self,
+            stack: vec![start],
+            visited,
+        }
+    }
+
+    /// # Panics
+    /// if start >= nodes_len
+    pub fn bfs_iter(&self, start: usize) -> BfsIter<'_, W> {
+        let mut visited = vec![false; self.nodes_len()];
+        visited[start] = true;
+        BfsIter {
+            graph: self,
+            queue: VecDeque::from([start]),
+            visited,
+        }
+    }
+
+    /// # Returns
+    /// number of edges on a shortest path from `start` to every node, None if unreachable.
+    ///
+    /// # Panics
+    /// if start >= nodes_len
+    pub fn bfs_levels(&self, start: usize) -> Vec<Option<usize>> {
+        let mut levels = vec![None; self.nodes_len()];
+        levels[start] = Some(0);
+        let mut queue = VecDeque::from([start]);
+        while let Some(u) = queue.pop_front() {
+            let next = levels[u].map(|l| l + 1);
+            for &v in self.neighbors(u) {
+                if levels[v].is_none() {
+                    levels[v] = next;
+                    queue.push_back(v);
+                }
+            }
+        }
+        levels
+    }
+
+    /// level synchronous parallel version of `bfs_levels`, every frontier is
+    /// expanded in parallel.
+    ///
+    /// # Panics
+    /// if start >= nodes_len
+    pub fn bfs_levels_par(&self, start: usize) -> Vec<Option<usize>>
+    where
+        W: Sync,
+    {
+        let levels: Vec<AtomicUsize> = (0..self.nodes_len())
+            .map(|_| AtomicUsize::new(usize::MAX))
+            .collect();
+        levels[start].store(0, Ordering::Relaxed);
+        let mut frontier = vec![start];
+        let mut level = 0;
+        while !frontier.is_empty() {
+            level += 1;
+            frontier = frontier
+                .par_iter()
+                .flat_map_iter(|&u| self.neighbors(u).iter().copied())
+                .filter(|&v| {
+                    levels[v]
+                        .compare_exchange(usize::MAX, level, Ordering::Relaxed, Ordering::Relaxed)
+                        .is_ok()
+                })
+                .collect();
+        }
+        levels
+            .into_iter()
+            .map(|l| Some(l.into_inner()).filter(|l| *l != usize::MAX))
+            .collect()
+    }
+
+    /// PageRank by power iteration, edge weights are ignored and nodes without
+    /// edges spread their rank over every node.
+    ///
+    /// # Returns
+    /// rank of every node, the ranks sum to 1.
+    pub fn pagerank(&self, damping: f64, tol: f64, max_iter: usize) -> Vec<f64> {
+        let ins = self.in_edges();
+        self.pagerank_with(damping, tol, max_iter, |ranks, contrib, base| {
+            for (v, rank) in ranks.iter_mut().enumerate() {
+                let sum: f64 = ins.neighbors(v).iter().map(|&u| contrib[u]).sum();
+                *rank = base + damping * sum;
+            }
+        })
+    }
+
+    /// parallel version of `pagerank`, gives the same result.
+    pub fn pagerank_par(&self, damping: f64, tol: f64, max_iter: usize) -> Vec<f64> {
+        let ins = self.in_edges();
+        self.pagerank_with(damping, tol, max_iter, |ranks, contrib, base| {
+            ranks.par_iter_mut().enumerate().for_each(|(v, rank)| {
+                let sum: f64 = ins.neighbors(v).iter().map(|&u| contrib[u]).sum();
+                *rank = base + damping * sum;
+            });
+        })
+    }
+
+    /// transposed graph without the weights.
+    fn in_edges(&self) -> CsrGraph<()> {
+        let mut edges = Vec::with_capacity(self.edges_len());
+        for from in 0..self.nodes_len() {
+            for &to in self.neighbors(from) {
+                edges.push((to, from, ()));
+            }
+        }
+        CsrGraph::from_edges(self.nodes_len(), edges)
+    }
+
+    /// power iteration shared by `pagerank` and `pagerank_par`, `step` fills the
+    /// new ranks from every node's contribution and the rank every node gets anyway.
+    fn pagerank_with(
+        &self,
+        damping: f64,
+        tol: f64,
+        max_iter: usize,
+        step: impl Fn(&mut [f64], &[f64], f64),
+    ) -> Vec<f64> {
+        let n = self.nodes_len();
+        if n == 0 {
+            return Vec::new();
+        }
+        let mut ranks = vec![1.0 / n as f64; n];
+        let mut next = vec![0.0; n];
+        let mut contrib = vec![0.0; n];
+        for _ in 0..max_iter {
+            let mut dangling = 0.0;
+            for u in 0..n {
+                match self.degree(u) {
+                    0 => {
+                        contrib[u] = 0.0;
+                        dangling += ranks[u];
+                    }
+                    d => contrib[u] = ranks[u] / d as f64,
+                }
+            }
+            let base = (1.0 - damping) / n as f64 + damping * dangling / n as f64;
+            step(&mut next, &contrib, base);
+
+            let diff: f64 = ranks
+                .iter()
+                .zip(next.iter())
+                .map(|(a, b)| (a - b).abs())
+                .sum();
+            std::mem::swap(&mut ranks, &mut next);
+            if diff < tol {
+                break;
+            }
+        }
+        ranks
+    }
+}
+
+pub struct DfsIter<'a, W> {
+    graph: &'a CsrGraph<W>,
+    stack: Vec<usize>,
+    visited: Vec<bool>,
+}
+
+impl<'a, W> Iterator for DfsIter<'a, W> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        for &neib in self.graph.neighbors(node) {
+            if !self.visited[neib] {
+                self.visited[neib] = true;
+                self.stack.push(neib);
+            }
+        }
+        Some(node)
+    }
+}
+
+pub struct BfsIter<'a, W> {
+    graph: &'a CsrGraph<W>,
+    queue: VecDeque<usize>,
+    visited: Vec<bool>,
+}
+
+impl<'a, W> Iterator for BfsIter<'a, W> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        for &neib in self.graph.neighbors(node) {
+            if !self.visited[neib] {
+                self.visited[neib] = true;
+                self.queue.push_back(neib);
+            }
+        }
+        Some(node)
+    }
+}
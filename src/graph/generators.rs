@@ -0,0 +1,225 @@
+//! graph families keyed by `0..n`, every node has `()` as value and every edge `weight`.
+//! the random ones take any `Rng`, a seeded one like `StdRng::seed_from_u64` gives
+//! the same graph for the same seed.
+
+use rand::{seq::SliceRandom, Rng};
+
+use super::hash_map::{undirected, Graph};
+
+fn empty<W>(n: usize) -> undirected::Graph<usize, (), W> {
+    let mut graph = undirected::Graph::new();
+    for i in 0..n {
+        graph.insert_node(i, ());
+    }
+    graph
+}
+
+fn empty_directed<W>(n: usize) -> Graph<usize, (), W> {
+    let mut graph = Graph::new();
+    for i in 0..n {
+        graph.insert_node(i, ());
+    }
+    graph
+}
+
+/// every pair of the `n` nodes is connected.
+pub fn complete<W: Clone>(n: usize, weight: W) -> undirected::Graph<usize, (), W> {
+    let mut graph = empty(n);
+    for i in 0..n {
+        for j in i + 1..n {
+            graph.insert_edge(i, j, weight.clone()).unwrap();
+        }
+    }
+    graph
+}
+
+/// `0 - 1 - ... - n-1`.
+pub fn path<W: Clone>(n: usize, weight: W) -> undirected::Graph<usize, (), W> {
+    let mut graph = empty(n);
+    for i in 1..n {
+        graph.insert_edge(i - 1, i, weight.clone()).unwrap();
+    }
+    graph
+}
+
+/// `path` with an edge between `n-1` and `0`, for n <= 2 it's the same as `path`.
+pub fn cycle<W: Clone>(n: usize, weight: W) -> undirected::Graph<usize, (), W> {
+    let mut graph = path(n, weight.clone());
+    if n > 2 {
+        graph.insert_edge(n - 1, 0, weight).unwrap();
+    }
+    graph
+}
+
+/// node `0` is connected to each of the other `n - 1` nodes.
+pub fn star<W: Clone>(n: usize, weight: W) -> undirected::Graph<usize, (), W> {
+    let mut graph = empty(n);
+    for i in 1..n {
+        graph.insert_edge(0, i, weight.clone()).unwrap();
+    }
+    graph
+}
+
+/// `rows` x `cols` lattice, node `row * cols + col` is connected to the ones
+/// next to it horizontally and vertically.
+pub fn grid<W: Clone>(rows: usize, cols: usize, weight: W) -> undirected::Graph<usize, (), W> {
+    let mut graph = empty(rows * cols);
+    for row in 0..rows {
+        for col in 0..cols {
+            let i = row * cols + col;
+            if col + 1 < cols {
+                graph.insert_edge(i, i + 1, weight.clone()).unwrap();
+            }
+            if row + 1 < rows {
+                graph.insert_edge(i, i + cols, weight.clone()).unwrap();
+            }
+        }
+    }
+    graph
+}
+
+/// every one of the nodes `0..m` is connected to every one of `m..m + n`.
+pub fn complete_bipartite<W: Clone>(
+    m: usize,
+    n: usize,
+    weight: W,
+) -> undirected::Graph<usize, (), W> {
+    let mut graph = empty(m + n);
+    for i in 0..m {
+        for j in m..m + n {
+            graph.insert_edge(i, j, weight.clone()).unwrap();
+        }
+    }
+    graph
+}
+
+/// Erdős–Rényi G(n, p), every pair of nodes is connected with probability `p`.
+///
+/// # Panics
+/// if `p` isn't in `[0, 1]`
+pub fn erdos_renyi<W, R>(
+    n: usize,
+    p: f64,
+    weight: W,
+    rng: &mut R,
+) -> undirected::Graph<usize, (), W>
+where
+    W: Clone,
+    R: Rng + ?Sized,
+{
+    assert!((0.0..=1.0).contains(&p));
+    let mut graph = empty(n);
+    for i in 0..n {
+        for j in i + 1..n {
+            if rng.gen_bool(p) {
+                graph.insert_edge(i, j, weight.clone()).unwrap();
+            }
+        }
+    }
+    graph
+}
+
+/// directed G(n, p), every ordered pair of different nodes is connected with probability `p`.
+///
+/// # Panics
+/// if `p` isn't in `[0, 1]`
+pub fn erdos_renyi_directed<W, R>(n: usize, p: f64, weight: W, rng: &mut R) -> Graph<usize, (), W>
+where
+    W: Clone,
+    R: Rng + ?Sized,
+{
+    assert!((0.0..=1.0).contains(&p));
+    let mut graph = empty_directed(n);
+    for i in 0..n {
+        for j in 0..n {
+            if i != j && rng.gen_bool(p) {
+                graph.insert_edge(i, j, weight.clone()).unwrap();
+            }
+        }
+    }
+    graph
+}
+
+/// Barabási–Albert preferential attachment. the first `m` nodes start without
+/// edges, then every later node is connected to `m` different earlier nodes picked
+/// with probability proportional to their degree.
+///
+/// # Panics
+/// if m == 0 || m >= n
+pub fn barabasi_albert<W, R>(
+    n: usize,
+    m: usize,
+    weight: W,
+    rng: &mut R,
+) -> undirected::Graph<usize, (), W>
+where
+    W: Clone,
+    R: Rng + ?Sized,
+{
+    assert!(m > 0 && m < n);
+    let mut graph = empty(n);
+    // every node once for each edge touching it
+    let mut ends: Vec<usize> = Vec::with_capacity(2 * m * (n - m));
+    let mut targets: Vec<usize> = (0..m).collect();
+    for source in m..n {
+        for &target in targets.iter() {
+            graph.insert_edge(source, target, weight.clone()).unwrap();
+            ends.push(target);
+            ends.push(source);
+        }
+        targets.clear();
+        while targets.len() < m {
+            let target = ends[rng.gen_range(0..ends.len())];
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+    }
+    graph
+}
+
+/// directed acyclic graph with an edge `i -> j` for every i < j with probability `p`,
+/// so `0..n` is a topological order.
+///
+/// # Panics
+/// if `p` isn't in `[0, 1]`
+pub fn random_dag<W, R>(n: usize, p: f64, weight: W, rng: &mut R) -> Graph<usize, (), W>
+where
+    W: Clone,
+    R: Rng + ?Sized,
+{
+    assert!((0.0..=1.0).contains(&p));
+    dag(&(0..n).collect::<Vec<usize>>(), p, weight, rng)
+}
+
+/// like `random_dag` with the topological order shuffled.
+///
+/// # Panics
+/// if `p` isn't in `[0, 1]`
+pub fn random_dag_shuffled<W, R>(n: usize, p: f64, weight: W, rng: &mut R) -> Graph<usize, (), W>
+where
+    W: Clone,
+    R: Rng + ?Sized,
+{
+    assert!((0.0..=1.0).contains(&p));
+    let mut order: Vec<usize> = (0..n).collect();
+    order.shuffle(rng);
+    dag(&order, p, weight, rng)
+}
+
+/// edge `order[i] -> order[j]` for every i < j with probability `p`.
+fn dag<W, R>(order: &[usize], p: f64, weight: W, rng: &mut R) -> Graph<usize, (), W>
+where
+    W: Clone,
+    R: Rng + ?Sized,
+{
+    let mut graph = empty_directed(order.len());
+    for (i, &from) in order.iter().enumerate() {
+        for &to in order[i + 1..].iter() {
+            if rng.gen_bool(p) {
+                graph.insert_edge(from, to, weight.clone()).unwrap();
+            }
+        }
+    }
+    graph
+}
@@ -0,0 +1,178 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::{BuildHasher, Hash},
+};
+
+use super::Graph;
+
+impl<K, T, W, S> Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    /// two-coloring by breadth first search, edges are treated as undirected.
+    ///
+    /// # Returns
+    /// side of every node, the ends of every edge are on different sides.
+    ///
+    /// # Error
+    /// if the graph isn't bipartite returns the nodes of an odd cycle in order,
+    /// the last one has an edge with the first. a self loop is a cycle of one node.
+    pub fn is_bipartite(&self) -> Result<HashMap<&K, bool>, Vec<&K>> {
+        two_coloring(self.iter().map(|(k, _)| k), &self.undirected_neighbors())
+    }
+
+    /// Hopcroft-Karp algorithm, the sides are the ones found by `is_bipartite`.
+    ///
+    /// # Returns
+    /// edges of a maximum matching, each in the direction it's stored in the graph.
+    ///
+    /// # Error
+    /// if the graph isn't bipartite returns an odd cycle like `is_bipartite`.
+    pub fn maximum_bipartite_matching(&self) -> Result<Vec<(&K, &K)>, Vec<&K>> {
+        let adj = self.undirected_neighbors();
+        let side = two_coloring(self.iter().map(|(k, _)| k), &adj)?;
+        let (right, left): (Vec<&K>, Vec<&K>) = self.iter().map(|(k, _)| k).partition(|k| side[*k]);
+        let index: HashMap<&K, usize> = right.iter().enumerate().map(|(i, k)| (*k, i)).collect();
+        let edges: Vec<Vec<usize>> = left
+            .iter()
+            .map(|k| adj[k].iter().map(|n| index[n]).collect())
+            .collect();
+
+        let matching = hopcroft_karp(&edges, right.len())
+            .into_iter()
+            .enumerate()
+            .filter_map(|(u, v)| Some((left[u], right[v?])))
+            .map(|(u, v)| match self.map[u].neibs.contains_key(v) {
+                true => (u, v),
+                false => (v, u),
+            })
+            .collect();
+        Ok(matching)
+    }
+}
+
+/// breadth first two coloring of `adj`, components are started from `keys` in order.
+fn two_coloring<'a, K>(
+    keys: impl Iterator<Item = &'a K>,
+    adj: &HashMap<&'a K, Vec<&'a K>>,
+) -> Result<HashMap<&'a K, bool>, Vec<&'a K>>
+where
+    K: Hash + Eq,
+{
+    let mut side: HashMap<&K, bool> = HashMap::with_capacity(adj.len());
+    let mut parent: HashMap<&K, &K> = HashMap::new();
+    for start in keys {
+        if side.contains_key(start) {
+            continue;
+        }
+        side.insert(start, false);
+        let mut queue = VecDeque::from([start]);
+        while let Some(u) = queue.pop_front() {
+            for &v in adj[u].iter() {
+                match side.get(v) {
+                    None => {
+                        side.insert(v, !side[u]);
+                        parent.insert(v, u);
+                        queue.push_back(v);
+                    }
+                    Some(s) if *s == side[u] => return Err(odd_cycle(&parent, u, v)),
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+    Ok(side)
+}
+
+/// cycle closed by the edge `u - v` whose ends are on the same side. both are
+/// at the same depth of the breadth first tree so walking up in lockstep meets
+/// at their lowest common ancestor.
+fn odd_cycle<'a, K: Hash + Eq>(parent: &HashMap<&'a K, &'a K>, u: &'a K, v: &'a K) -> Vec<&'a K> {
+    let (mut a, mut b) = (u, v);
+    let (mut left, mut right) = (vec![a], vec![b]);
+    while a != b {
+        a = parent[a];
+        b = parent[b];
+        left.push(a);
+        right.push(b);
+    }
+    right.pop();
+    left.reverse();
+    left.extend(right);
+    left
+}
+
+/// # Returns
+/// the right node matched with every left node.
+fn hopcroft_karp(adj: &[Vec<usize>], right_len: usize) -> Vec<Option<usize>> {
+    let mut pair_left: Vec<Option<usize>> = vec![None; adj.len()];
+    let mut pair_right: Vec<Option<usize>> = vec![None; right_len];
+    let mut dist = vec![usize::MAX; adj.len()];
+    loop {
+        // layers of left nodes by alternating paths from the free ones
+        let mut queue = VecDeque::new();
+        for u in 0..adj.len() {
+            dist[u] = match pair_left[u] {
+                None => {
+                    queue.push_back(u);
+                    0
+                }
+                Some(_) => usize::MAX,
+            };
+        }
+        let mut found = false;
+        while let Some(u) = queue.pop_front() {
+            for &v in adj[u].iter() {
+                match pair_right[v] {
+                    None => found = true,
+                    Some(w) if dist[w] == usize::MAX => {
+                        dist[w] = dist[u] + 1;
+                        queue.push_back(w);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        if !found {
+            return pair_left;
+        }
+
+        // augmenting paths along the layers with an explicit stack instead of recursion
+        let mut next = vec![0; adj.len()];
+        for root in 0..adj.len() {
+            if pair_left[root].is_some() {
+                continue;
+            }
+            let mut path: Vec<(usize, usize)> = Vec::new();
+            let mut u = root;
+            loop {
+                let Some(&v) = adj[u].get(next[u]) else {
+                    // dead end, never try `u` again in this phase
+                    dist[u] = usize::MAX;
+                    match path.pop() {
+                        Some((prev, _)) => u = prev,
+                        None => break,
+                    }
+                    continue;
+                };
+                next[u] += 1;
+                match pair_right[v] {
+                    None => {
+                        path.push((u, v));
+                        for &(a, b) in path.iter() {
+                            pair_left[a] = Some(b);
+                            pair_right[b] = Some(a);
+                        }
+                        break;
+                    }
+                    Some(w) if dist[w] == dist[u] + 1 => {
+                        path.push((u, v));
+                        u = w;
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+}
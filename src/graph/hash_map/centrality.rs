@@ -0,0 +1,206 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::{BuildHasher, Hash},
+};
+
+use super::Graph;
+use crate::graph::csr::CsrGraph;
+
+impl<K, T, W, S> Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    /// PageRank by power iteration, weights are ignored. the rank of nodes without
+    /// outgoing edges is spread over every node.
+    ///
+    /// # Returns
+    /// rank of every node, the ranks sum to 1. stops when the sum of the absolute
+    /// changes of one iteration is below `tol` or after `max_iter` iterations.
+    pub fn pagerank(&self, damping: f64, tol: f64, max_iter: usize) -> HashMap<K, f64>
+    where
+        W: Clone,
+    {
+        let (csr, keys) = CsrGraph::from_graph(self);
+        keys.into_iter()
+            .zip(csr.pagerank(damping, tol, max_iter))
+            .collect()
+    }
+
+    /// `pagerank` with every iteration computed in parallel.
+    pub fn pagerank_par(&self, damping: f64, tol: f64, max_iter: usize) -> HashMap<K, f64>
+    where
+        W: Clone,
+    {
+        let (csr, keys) = CsrGraph::from_graph(self);
+        keys.into_iter()
+            .zip(csr.pagerank_par(damping, tol, max_iter))
+            .collect()
+    }
+
+    /// Brandes algorithm, number of shortest paths between other nodes that go
+    /// through every node. paths are counted by edges, weights are ignored.
+    ///
+    /// # Returns
+    /// if `normalized` the counts are divided by (n - 1)(n - 2), the number of
+    /// ordered pairs of other nodes.
+    pub fn betweenness_centrality(&self, normalized: bool) -> HashMap<K, f64> {
+        let (keys, adj) = self.indexed();
+        let n = keys.len();
+        let mut centrality = vec![0.0; n];
+        let mut order: Vec<usize> = Vec::with_capacity(n);
+        let mut preds: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut paths = vec![0.0; n];
+        let mut dist = vec![usize::MAX; n];
+        let mut delta = vec![0.0; n];
+
+        for s in 0..n {
+            order.clear();
+            for i in 0..n {
+                preds[i].clear();
+                paths[i] = 0.0;
+                dist[i] = usize::MAX;
+                delta[i] = 0.0;
+            }
+            paths[s] = 1.0;
+            dist[s] = 0;
+            let mut queue = VecDeque::from([s]);
+            while let Some(u) = queue.pop_front() {
+                order.push(u);
+                for &v in adj[u].iter() {
+                    if dist[v] == usize::MAX {
+                        dist[v] = dist[u] + 1;
+                        queue.push_back(v);
+                    }
+                    if dist[v] == dist[u] + 1 {
+                        paths[v] += paths[u];
+                        preds[v].push(u);
+                    }
+                }
+            }
+            // dependencies accumulate from the farthest nodes back to `s`
+            for &v in order.iter().rev() {
+                for &u in preds[v].iter() {
+                    delta[u] += paths[u] / paths[v] * (1.0 + delta[v]);
+                }
+                if v != s {
+                    centrality[v] += delta[v];
+                }
+            }
+        }
+
+        if normalized && n > 2 {
+            let pairs = ((n - 1) * (n - 2)) as f64;
+            centrality.iter_mut().for_each(|c| *c /= pairs);
+        }
+        zip_keys(keys, centrality)
+    }
+
+    /// inverse of the average distance to the nodes reachable by outgoing edges,
+    /// scaled by the fraction of the other nodes that are reachable so nodes in
+    /// small components don't get high values. distances are counted by edges.
+    ///
+    /// # Returns
+    /// 0 for nodes that reach no other node.
+    pub fn closeness_centrality(&self) -> HashMap<K, f64> {
+        let (keys, adj) = self.indexed();
+        let n = keys.len();
+        let centrality = (0..n)
+            .map(|s| {
+                let (reached, total) = bfs_distances(&adj, s)
+                    .into_iter()
+                    .filter(|&d| d != 0 && d != usize::MAX)
+                    .fold((0, 0), |(r, t), d| (r + 1, t + d));
+                match total {
+                    0 => 0.0,
+                    _ => (reached as f64 / total as f64) * (reached as f64 / (n - 1) as f64),
+                }
+            })
+            .collect();
+        zip_keys(keys, centrality)
+    }
+
+    /// sum of the inverse distances to every other node by outgoing edges,
+    /// unreachable nodes add 0. distances are counted by edges.
+    pub fn harmonic_centrality(&self) -> HashMap<K, f64> {
+        let (keys, adj) = self.indexed();
+        let centrality = (0..keys.len())
+            .map(|s| {
+                bfs_distances(&adj, s)
+                    .into_iter()
+                    .filter(|&d| d != 0 && d != usize::MAX)
+                    .map(|d| 1.0 / d as f64)
+                    .sum()
+            })
+            .collect();
+        zip_keys(keys, centrality)
+    }
+
+    /// number of incoming and outgoing edges divided by n - 1, a self loop counts twice.
+    /// a graph of one node gives it 1.
+    pub fn degree_centrality(&self) -> HashMap<K, f64> {
+        self.degree_centrality_by(|out, inc| out + inc)
+    }
+
+    /// number of incoming edges divided by n - 1.
+    pub fn in_degree_centrality(&self) -> HashMap<K, f64> {
+        self.degree_centrality_by(|_, inc| inc)
+    }
+
+    /// number of outgoing edges divided by n - 1.
+    pub fn out_degree_centrality(&self) -> HashMap<K, f64> {
+        self.degree_centrality_by(|out, _| out)
+    }
+
+    fn degree_centrality_by(&self, degree: impl Fn(usize, usize) -> usize) -> HashMap<K, f64> {
+        let (keys, adj) = self.indexed();
+        let n = keys.len();
+        if n == 1 {
+            return zip_keys(keys, vec![1.0]);
+        }
+        let mut in_degrees = vec![0; n];
+        for &v in adj.iter().flatten() {
+            in_degrees[v] += 1;
+        }
+        let centrality = (0..n)
+            .map(|i| degree(adj[i].len(), in_degrees[i]) as f64 / (n - 1) as f64)
+            .collect();
+        zip_keys(keys, centrality)
+    }
+
+    /// keys in iteration order and the indexes of every node's neighbors,
+    /// edges to nodes that aren't in the graph are skipped.
+    fn indexed(&self) -> (Vec<&K>, Vec<Vec<usize>>) {
+        let keys: Vec<&K> = self.iter().map(|(k, _)| k).collect();
+        let index: HashMap<&K, usize> = keys.iter().enumerate().map(|(i, k)| (*k, i)).collect();
+        let adj = self
+            .iter()
+            .map(|(_, node)| {
+                self.neighbors_of(node)
+                    .filter_map(|(n, _)| index.get(n).copied())
+                    .collect()
+            })
+            .collect();
+        (keys, adj)
+    }
+}
+
+fn zip_keys<K: Hash + Eq + Clone>(keys: Vec<&K>, vals: Vec<f64>) -> HashMap<K, f64> {
+    keys.into_iter().cloned().zip(vals).collect()
+}
+
+/// distance by edges from `s` to every node, usize::MAX if unreachable.
+fn bfs_distances(adj: &[Vec<usize>], s: usize) -> Vec<usize> {
+    let mut dist = vec![usize::MAX; adj.len()];
+    dist[s] = 0;
+    let mut queue = VecDeque::from([s]);
+    while let Some(u) = queue.pop_front() {
+        for &v in adj[u].iter() {
+            if dist[v] == usize::MAX {
+                dist[v] = dist[u] + 1;
+                queue.push_back(v);
+            }
+        }
+    }
+    dist
+}
@@ -0,0 +1,224 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::{BuildHasher, Hash},
+};
+
+use rayon::prelude::*;
+
+use super::Graph;
+use crate::graph::algo;
+
+/// results of one low-link depth first search over the whole graph.
+struct LowLink<'a, K> {
+    bridges: Vec<(&'a K, &'a K)>,
+    cut_points: HashSet<&'a K>,
+    components: Vec<Vec<&'a K>>,
+}
+
+impl<K, T, W, S> Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    /// weakly connected components, edges are treated as undirected.
+    ///
+    /// # Returns
+    /// nodes of every component in the iteration order of the graph, components
+    /// ordered by their first node.
+    pub fn connected_components(&self) -> Vec<Vec<&K>> {
+        let (keys, adj) = algo::undirected_adjacency(self);
+        let mut labels = vec![usize::MAX; keys.len()];
+        for start in 0..keys.len() {
+            if labels[start] != usize::MAX {
+                continue;
+            }
+            labels[start] = start;
+            let mut queue = VecDeque::from([start]);
+            while let Some(v) = queue.pop_front() {
+                for &u in adj[v].iter() {
+                    if labels[u] == usize::MAX {
+                        labels[u] = start;
+                        queue.push_back(u);
+                    }
+                }
+            }
+        }
+        group_components(keys, labels)
+    }
+
+    /// parallel version of `connected_components` by label propagation, gives the
+    /// same result. every round each node takes the smallest label among its own and
+    /// its neighbors' in parallel, then jumps to the label of its label.
+    pub fn connected_components_par(&self) -> Vec<Vec<&K>>
+    where
+        K: Sync,
+    {
+        let (keys, adj) = algo::undirected_adjacency(self);
+        // a label is the index of a node in the same component and never above the
+        // node's own, so the smallest index of the component wins
+        let mut labels: Vec<usize> = (0..keys.len()).collect();
+        loop {
+            let next: Vec<usize> = (0..keys.len())
+                .into_par_iter()
+                .map(|v| {
+                    adj[v]
+                        .iter()
+                        .map(|&u| labels[u])
+                        .fold(labels[v], usize::min)
+                })
+                .collect();
+            let next: Vec<usize> = next.par_iter().map(|&l| next[l]).collect();
+            if next == labels {
+                break;
+            }
+            labels = next;
+        }
+        group_components(keys, labels)
+    }
+
+    /// # Returns
+    /// true if the graph has at most one weakly connected component.
+    pub fn is_connected(&self) -> bool {
+        self.connected_components().len() <= 1
+    }
+
+    /// edges whose removal disconnects their component. edges are treated as
+    /// undirected, `x -> y` and `y -> x` count as one edge.
+    ///
+    /// # Returns
+    /// every bridge in the direction it's stored in the graph.
+    pub fn bridges(&self) -> Vec<(&K, &K)> {
+        self.low_link()
+            .bridges
+            .into_iter()
+            .map(|(x, y)| match self.map[x].neibs.contains_key(y) {
+                true => (x, y),
+                false => (y, x),
+            })
+            .collect()
+    }
+
+    /// nodes whose removal disconnects their component, edges are treated as undirected.
+    pub fn articulation_points(&self) -> Vec<&K> {
+        let cut_points = self.low_link().cut_points;
+        self.iter()
+            .map(|(k, _)| k)
+            .filter(|k| cut_points.contains(k))
+            .collect()
+    }
+
+    /// maximal sets of edges where no single node removal disconnects them,
+    /// edges are treated as undirected.
+    ///
+    /// # Returns
+    /// nodes of every component, articulation points are in more than one of
+    /// them and nodes without edges in none.
+    pub fn biconnected_components(&self) -> Vec<Vec<&K>> {
+        self.low_link().components
+    }
+
+    /// Tarjan's low-link depth first search with an explicit stack. `low[x]` is the
+    /// smallest discovery time reachable from the subtree of `x` with one back edge.
+    fn low_link(&self) -> LowLink<'_, K> {
+        let adj = self.undirected_neighbors();
+        let mut disc: HashMap<&K, usize> = HashMap::with_capacity(adj.len());
+        let mut low: HashMap<&K, usize> = HashMap::with_capacity(adj.len());
+        let mut clock = 0;
+        let mut ret = LowLink {
+            bridges: Vec::new(),
+            cut_points: HashSet::new(),
+            components: Vec::new(),
+        };
+
+        for (root, _) in self.iter() {
+            if disc.contains_key(root) {
+                continue;
+            }
+            disc.insert(root, clock);
+            low.insert(root, clock);
+            clock += 1;
+            let mut root_children = 0;
+            // (node, parent, index of the next neighbor to visit)
+            let mut stack: Vec<(&K, Option<&K>, usize)> = vec![(root, None, 0)];
+            let mut edges: Vec<(&K, &K)> = Vec::new();
+
+            while let Some((key, parent, next)) = stack.last_mut() {
+                let (key, parent) = (*key, *parent);
+                if let Some(&neib) = adj[key].get(*next) {
+                    *next += 1;
+                    if neib == key || Some(neib) == parent {
+                        continue;
+                    }
+                    match disc.get(neib) {
+                        None => {
+                            disc.insert(neib, clock);
+                            low.insert(neib, clock);
+                            clock += 1;
+                            edges.push((key, neib));
+                            stack.push((neib, Some(key), 0));
+                            if parent.is_none() {
+                                root_children += 1;
+                            }
+                        }
+                        Some(&d) if d < disc[key] => {
+                            let l = low.get_mut(key).unwrap();
+                            *l = (*l).min(d);
+                            edges.push((key, neib));
+                        }
+                        // a descendant, the edge was pushed from its side
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+
+                stack.pop();
+                let Some(parent) = parent else {
+                    continue;
+                };
+                let key_low = low[key];
+                let l = low.get_mut(parent).unwrap();
+                *l = (*l).min(key_low);
+                if key_low > disc[parent] {
+                    ret.bridges.push((parent, key));
+                }
+                if key_low >= disc[parent] {
+                    if parent != root {
+                        ret.cut_points.insert(parent);
+                    }
+                    let mut seen: HashSet<&K> = HashSet::new();
+                    let mut component = Vec::new();
+                    while let Some((x, y)) = edges.pop() {
+                        for z in [x, y] {
+                            if seen.insert(z) {
+                                component.push(z);
+                            }
+                        }
+                        if (x, y) == (parent, key) {
+                            break;
+                        }
+                    }
+                    ret.components.push(component);
+                }
+            }
+            if root_children > 1 {
+                ret.cut_points.insert(root);
+            }
+        }
+        ret
+    }
+}
+
+/// nodes grouped by `labels`, the label of every node is the smallest index of
+/// its component.
+fn group_components<K>(keys: Vec<&K>, labels: Vec<usize>) -> Vec<Vec<&K>> {
+    let mut components: Vec<Vec<&K>> = Vec::new();
+    let mut slot = vec![0; keys.len()];
+    for (v, key) in keys.into_iter().enumerate() {
+        if labels[v] == v {
+            slot[v] = components.len();
+            components.push(Vec::new());
+        }
+        components[slot[labels[v]]].push(key);
+    }
+    components
+}
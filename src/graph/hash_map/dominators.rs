@@ -0,0 +1,219 @@
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
+use crate::tree::lca::LcaIndex;
+
+use super::Graph;
+
+const NONE: usize = usize::MAX;
+
+/// dominator tree of the nodes reachable from `root`, made by `Graph::dominators`.
+/// a node `a` dominates `b` if every path from the root to `b` passes through `a`.
+#[derive(Debug, Clone)]
+pub struct Dominators<'a, K> {
+    root: &'a K,
+    /// immediate dominator of every reachable node but the root.
+    idom: HashMap<&'a K, &'a K>,
+    /// dominance frontier of every reachable node.
+    frontiers: HashMap<&'a K, Vec<&'a K>>,
+}
+
+impl<'a, K> Dominators<'a, K>
+where
+    K: Hash + Eq,
+{
+    pub fn root(&self) -> &'a K {
+        self.root
+    }
+
+    /// # Returns
+    /// closest strict dominator of `key`, its parent in the dominator tree.
+    /// None if `key` is the root or isn't reachable from it.
+    pub fn immediate_dominator(&self, key: &K) -> Option<&'a K> {
+        self.idom.get(key).copied()
+    }
+
+    /// # Returns
+    /// every dominator of `key` from itself up to the root, None if `key` isn't
+    /// reachable from the root.
+    pub fn dominators(&self, key: &K) -> Option<Vec<&'a K>> {
+        let mut key = *self.frontiers.get_key_value(key)?.0;
+        let mut chain = vec![key];
+        while let Some(&idom) = self.idom.get(key) {
+            chain.push(idom);
+            key = idom;
+        }
+        Some(chain)
+    }
+
+    /// walks the dominator tree up from `b`, `lca_index` answers it faster for
+    /// many queries.
+    ///
+    /// # Returns
+    /// true if `a` dominates `b`, a node dominates itself. false if either isn't
+    /// reachable from the root.
+    pub fn dominates(&self, a: &K, b: &K) -> bool {
+        self.dominators(b)
+            .is_some_and(|chain| chain.into_iter().any(|k| k == a))
+    }
+
+    /// # Returns
+    /// nodes where the dominance of `key` ends, every node with a predecessor
+    /// dominated by `key` that isn't strictly dominated by it. None if `key`
+    /// isn't reachable from the root.
+    pub fn dominance_frontier(&self, key: &K) -> Option<&[&'a K]> {
+        self.frontiers.get(key).map(Vec::as_slice)
+    }
+
+    /// lowest common ancestor index of the dominator tree, `is_ancestor(a, b)` of
+    /// it is whether `a` dominates `b`.
+    pub fn lca_index(&self) -> LcaIndex<&'a K> {
+        let mut children: HashMap<&K, Vec<&K>> = HashMap::new();
+        for (&key, &idom) in self.idom.iter() {
+            children.entry(idom).or_default().push(key);
+        }
+        LcaIndex::new(self.root, |key| children.remove(key).unwrap_or_default())
+    }
+}
+
+impl<K, T, W, S> Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    /// dominator tree and dominance frontiers from `entry` by Lengauer–Tarjan with
+    /// path compression, `O(e log n)`. nodes not reachable from `entry` are left out.
+    ///
+    /// # Returns
+    /// None if graph doesn't contain `entry`.
+    pub fn dominators(&self, entry: &K) -> Option<Dominators<'_, K>> {
+        let (entry, _) = self.map.get_key_value(entry)?;
+
+        // depth first numbering, a node is known by its number from here on
+        let mut vertex: Vec<&K> = vec![entry];
+        let mut number: HashMap<&K, usize> = HashMap::from([(entry, 0)]);
+        let mut parent: Vec<usize> = vec![NONE];
+        let mut stack = vec![(0, self.out_neighbors(entry).unwrap())];
+        while let Some((v, neibs)) = stack.last_mut() {
+            let v = *v;
+            match neibs.next() {
+                Some((next, _)) => {
+                    if let (false, Some((next, node))) =
+                        (number.contains_key(next), self.map.get_key_value(next))
+                    {
+                        number.insert(next, vertex.len());
+                        stack.push((vertex.len(), self.neighbors_of(node)));
+                        vertex.push(next);
+                        parent.push(v);
+                    }
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+        let n = vertex.len();
+        let mut preds: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for (v, key) in vertex.iter().enumerate() {
+            for (neib, _) in self.out_neighbors(key).unwrap() {
+                if let Some(&w) = number.get(neib) {
+                    preds[w].push(v);
+                }
+            }
+        }
+
+        let mut forest = Forest {
+            semi: (0..n).collect(),
+            label: (0..n).collect(),
+            ancestor: vec![NONE; n],
+        };
+        let mut idom = vec![NONE; n];
+        let mut bucket: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for w in (1..n).rev() {
+            for &v in preds[w].iter() {
+                let u = forest.eval(v);
+                if forest.semi[u] < forest.semi[w] {
+                    forest.semi[w] = forest.semi[u];
+                }
+            }
+            bucket[forest.semi[w]].push(w);
+            forest.ancestor[w] = parent[w];
+            for v in std::mem::take(&mut bucket[parent[w]]) {
+                let u = forest.eval(v);
+                idom[v] = match forest.semi[u] < forest.semi[v] {
+                    true => u,
+                    false => parent[w],
+                };
+            }
+        }
+        for w in 1..n {
+            if idom[w] != forest.semi[w] {
+                idom[w] = idom[idom[w]];
+            }
+        }
+
+        // walking up from every predecessor of a node to its immediate dominator,
+        // all the way up for the root since nothing strictly dominates it
+        let mut frontiers: Vec<Vec<&K>> = vec![Vec::new(); n];
+        for w in 0..n {
+            for &p in preds[w].iter() {
+                let mut runner = p;
+                while runner != idom[w] {
+                    if frontiers[runner].last() != Some(&vertex[w]) {
+                        frontiers[runner].push(vertex[w]);
+                    }
+                    match runner {
+                        0 => break,
+                        _ => runner = idom[runner],
+                    }
+                }
+            }
+        }
+
+        Some(Dominators {
+            root: entry,
+            idom: (1..n).map(|w| (vertex[w], vertex[idom[w]])).collect(),
+            frontiers: vertex.iter().copied().zip(frontiers).collect(),
+        })
+    }
+}
+
+/// forest of the depth first tree that grows as Lengauer–Tarjan links nodes.
+struct Forest {
+    semi: Vec<usize>,
+    /// node with the smallest semidominator on the compressed path to the ancestor.
+    label: Vec<usize>,
+    ancestor: Vec<usize>,
+}
+
+impl Forest {
+    /// # Returns
+    /// node with the smallest semidominator on the path from the root of the tree
+    /// of `v` to `v`, the root itself left out.
+    fn eval(&mut self, v: usize) -> usize {
+        if self.ancestor[v] == NONE {
+            return v;
+        }
+        self.compress(v);
+        self.label[v]
+    }
+
+    fn compress(&mut self, v: usize) {
+        let mut path = Vec::new();
+        let mut x = v;
+        while self.ancestor[self.ancestor[x]] != NONE {
+            path.push(x);
+            x = self.ancestor[x];
+        }
+        // nodes closer to the root first
+        while let Some(x) = path.pop() {
+            let a = self.ancestor[x];
+            if self.semi[self.label[a]] < self.semi[self.label[x]] {
+                self.label[x] = self.label[a];
+            }
+            self.ancestor[x] = self.ancestor[a];
+        }
+    }
+}
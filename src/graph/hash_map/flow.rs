@@ -0,0 +1,360 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::{BuildHasher, Hash},
+    ops::{Add, Mul, Sub},
+};
+
+use super::{EdgeErr, Graph};
+
+#[derive(Debug, Clone)]
+pub struct MaxFlow<'a, K, W> {
+    pub value: W,
+    /// flow through every edge of the graph, keyed by `(from, to)`.
+    pub flows: HashMap<(&'a K, &'a K), W>,
+    /// nodes on the source side of a minimum cut, the rest are on the sink side.
+    pub source_side: HashSet<&'a K>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MinCostFlow<'a, K, W> {
+    pub value: W,
+    pub cost: W,
+    /// flow through every edge of the graph, keyed by `(from, to)`.
+    pub flows: HashMap<(&'a K, &'a K), W>,
+}
+
+/// residual network where arc `2i` is the `i`th edge and arc `2i + 1` its reverse.
+/// a reverse arc costs the negated cost of its edge, the sign is told by the parity
+/// of the arc so the cost type doesn't have to be signed.
+struct Residual<'a, K, W> {
+    keys: Vec<&'a K>,
+    index: HashMap<&'a K, usize>,
+    edges: Vec<(&'a K, &'a K)>,
+    head: Vec<Vec<usize>>,
+    to: Vec<usize>,
+    cap: Vec<W>,
+    /// cost of every edge.
+    cost: Vec<W>,
+}
+
+impl<'a, K, W> Residual<'a, K, W>
+where
+    K: Hash + Eq + Clone,
+    W: Copy + Ord + Default + Add<Output = W> + Sub<Output = W>,
+{
+    fn new<T, E, S>(
+        graph: &'a Graph<K, T, E, S>,
+        source: &K,
+        sink: &K,
+        cap_cost: impl Fn(&E) -> (W, W),
+    ) -> Result<Self, EdgeErr>
+    where
+        S: BuildHasher + Default,
+    {
+        if !graph.contains(source) {
+            return Err(EdgeErr::FromNone);
+        }
+        if !graph.contains(sink) {
+            return Err(EdgeErr::ToNone);
+        }
+        let keys: Vec<&K> = graph.iter().map(|(k, _)| k).collect();
+        let index: HashMap<&K, usize> = keys.iter().enumerate().map(|(i, k)| (*k, i)).collect();
+        let mut residual = Self {
+            head: vec![Vec::new(); keys.len()],
+            keys,
+            index,
+            edges: Vec::new(),
+            to: Vec::new(),
+            cap: Vec::new(),
+            cost: Vec::new(),
+        };
+        for (from, node) in graph.iter() {
+            for (to, weight) in graph.neighbors_of(node) {
+                let Some(&v) = residual.index.get(to) else {
+                    continue;
+                };
+                let u = residual.index[from];
+                let (cap, cost) = cap_cost(weight);
+                residual.edges.push((from, to));
+                residual.head[u].push(residual.to.len());
+                residual.to.push(v);
+                residual.cap.push(cap);
+                residual.cost.push(cost);
+                residual.head[v].push(residual.to.len());
+                residual.to.push(u);
+                residual.cap.push(W::default());
+            }
+        }
+        Ok(residual)
+    }
+
+    fn has_cap(&self, arc: usize) -> bool {
+        self.cap[arc] > W::default()
+    }
+
+    fn push(&mut self, arc: usize, flow: W) {
+        self.cap[arc] = self.cap[arc] - flow;
+        self.cap[arc ^ 1] = self.cap[arc ^ 1] + flow;
+    }
+
+    /// # Returns
+    /// parent arc of every node reached from `s` on a shortest path of arcs with capacity.
+    fn bfs(&self, s: usize) -> Vec<Option<usize>> {
+        let mut parent = vec![None; self.keys.len()];
+        let mut visited = vec![false; self.keys.len()];
+        let mut queue = VecDeque::from([s]);
+        visited[s] = true;
+        while let Some(u) = queue.pop_front() {
+            for &arc in self.head[u].iter() {
+                let v = self.to[arc];
+                if !visited[v] && self.has_cap(arc) {
+                    visited[v] = true;
+                    parent[v] = Some(arc);
+                    queue.push_back(v);
+                }
+            }
+        }
+        parent
+    }
+
+    fn bottleneck(&self, path: &[usize]) -> W {
+        path.iter()
+            .map(|&arc| self.cap[arc])
+            .min()
+            .unwrap_or_default()
+    }
+
+    fn path_to(&self, parent: &[Option<usize>], t: usize) -> Vec<usize> {
+        let mut path = Vec::new();
+        let mut v = t;
+        while let Some(arc) = parent[v] {
+            path.push(arc);
+            v = self.to[arc ^ 1];
+        }
+        path
+    }
+
+    fn edmonds_karp(&mut self, s: usize, t: usize) -> W {
+        let mut total = W::default();
+        if s == t {
+            return total;
+        }
+        loop {
+            let parent = self.bfs(s);
+            if parent[t].is_none() {
+                return total;
+            }
+            let path = self.path_to(&parent, t);
+            let flow = self.bottleneck(&path);
+            for &arc in path.iter() {
+                self.push(arc, flow);
+            }
+            total = total + flow;
+        }
+    }
+
+    fn dinic(&mut self, s: usize, t: usize) -> W {
+        let mut total = W::default();
+        if s == t {
+            return total;
+        }
+        loop {
+            let mut level = vec![usize::MAX; self.keys.len()];
+            level[s] = 0;
+            let mut queue = VecDeque::from([s]);
+            while let Some(u) = queue.pop_front() {
+                for &arc in self.head[u].iter() {
+                    let v = self.to[arc];
+                    if level[v] == usize::MAX && self.has_cap(arc) {
+                        level[v] = level[u] + 1;
+                        queue.push_back(v);
+                    }
+                }
+            }
+            if level[t] == usize::MAX {
+                return total;
+            }
+
+            // blocking flow with an explicit stack instead of recursion
+            let mut next_arc = vec![0; self.keys.len()];
+            let mut path: Vec<usize> = Vec::new();
+            let mut u = s;
+            loop {
+                if u == t {
+                    let flow = self.bottleneck(&path);
+                    for &arc in path.iter() {
+                        self.push(arc, flow);
+                    }
+                    total = total + flow;
+                    path.clear();
+                    u = s;
+                    continue;
+                }
+                let mut advanced = false;
+                while let Some(&arc) = self.head[u].get(next_arc[u]) {
+                    let v = self.to[arc];
+                    if self.has_cap(arc) && level[v] == level[u] + 1 {
+                        path.push(arc);
+                        u = v;
+                        advanced = true;
+                        break;
+                    }
+                    next_arc[u] += 1;
+                }
+                if advanced {
+                    continue;
+                }
+                if u == s {
+                    break;
+                }
+                level[u] = usize::MAX;
+                let arc = path.pop().unwrap();
+                u = self.to[arc ^ 1];
+                next_arc[u] += 1;
+            }
+        }
+    }
+
+    fn flows(&self) -> HashMap<(&'a K, &'a K), W> {
+        let mut flows: HashMap<(&K, &K), W> = HashMap::with_capacity(self.edges.len());
+        for (i, &edge) in self.edges.iter().enumerate() {
+            let flow = flows.entry(edge).or_default();
+            *flow = *flow + self.cap[2 * i + 1];
+        }
+        flows
+    }
+
+    fn into_max_flow(self, s: usize, value: W) -> MaxFlow<'a, K, W> {
+        let parent = self.bfs(s);
+        let source_side = self
+            .keys
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i == s || parent[*i].is_some())
+            .map(|(_, k)| *k)
+            .collect();
+        MaxFlow {
+            value,
+            flows: self.flows(),
+            source_side,
+        }
+    }
+}
+
+impl<K, T, W, S> Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+    W: Copy + Ord + Default + Add<Output = W> + Sub<Output = W>,
+{
+    /// maximum flow from `source` to `sink` using Dinic's algorithm, edge weights are capacities.
+    ///
+    /// # Error
+    /// if graph doesn't contain `source` returns `Err(EdgeErr::FromNone)`.
+    /// if graph doesn't contain `sink` returns `Err(EdgeErr::ToNone)`.
+    pub fn max_flow<'a>(&'a self, source: &K, sink: &K) -> Result<MaxFlow<'a, K, W>, EdgeErr> {
+        let mut residual = Residual::new(self, source, sink, |w| (*w, W::default()))?;
+        let (s, t) = (residual.index[source], residual.index[sink]);
+        let value = residual.dinic(s, t);
+        Ok(residual.into_max_flow(s, value))
+    }
+
+    /// maximum flow from `source` to `sink` using the Edmonds-Karp algorithm, edge weights are capacities.
+    ///
+    /// # Error
+    /// if graph doesn't contain `source` returns `Err(EdgeErr::FromNone)`.
+    /// if graph doesn't contain `sink` returns `Err(EdgeErr::ToNone)`.
+    pub fn max_flow_edmonds_karp<'a>(
+        &'a self,
+        source: &K,
+        sink: &K,
+    ) -> Result<MaxFlow<'a, K, W>, EdgeErr> {
+        let mut residual = Residual::new(self, source, sink, |w| (*w, W::default()))?;
+        let (s, t) = (residual.index[source], residual.index[sink]);
+        let value = residual.edmonds_karp(s, t);
+        Ok(residual.into_max_flow(s, value))
+    }
+}
+
+impl<K, T, W, S> Graph<K, T, (W, W), S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+    W: Copy + Ord + Default + Add<Output = W> + Sub<Output = W> + Mul<Output = W>,
+{
+    /// maximum flow of minimum cost from `source` to `sink` by successive shortest paths,
+    /// edge weights are `(capacity, cost)`. costs of a signed type may be negative as
+    /// long as they don't form a negative cycle, unsigned cost types work as well.
+    ///
+    /// # Error
+    /// if graph doesn't contain `source` returns `Err(EdgeErr::FromNone)`.
+    /// if graph doesn't contain `sink` returns `Err(EdgeErr::ToNone)`.
+    pub fn min_cost_max_flow<'a>(
+        &'a self,
+        source: &K,
+        sink: &K,
+    ) -> Result<MinCostFlow<'a, K, W>, EdgeErr> {
+        let mut residual = Residual::new(self, source, sink, |w| *w)?;
+        let (s, t) = (residual.index[source], residual.index[sink]);
+        let (mut value, mut cost) = (W::default(), W::default());
+        let n = residual.keys.len();
+        if s == t {
+            return Ok(MinCostFlow {
+                value,
+                cost,
+                flows: residual.flows(),
+            });
+        }
+
+        loop {
+            // Bellman-Ford, reverse arcs have negative costs. with no negative cycle
+            // a distance is never less than 0 so unsigned costs don't underflow
+            let mut dist: Vec<Option<W>> = vec![None; n];
+            let mut parent: Vec<Option<usize>> = vec![None; n];
+            dist[s] = Some(W::default());
+            for _ in 0..n {
+                let mut relaxed = false;
+                for u in 0..n {
+                    let Some(du) = dist[u] else {
+                        continue;
+                    };
+                    for &arc in residual.head[u].iter() {
+                        if !residual.has_cap(arc) {
+                            continue;
+                        }
+                        let v = residual.to[arc];
+                        let new_dist = match arc & 1 {
+                            0 => du + residual.cost[arc / 2],
+                            _ => du - residual.cost[arc / 2],
+                        };
+                        if dist[v].is_none_or(|dv| new_dist < dv) {
+                            dist[v] = Some(new_dist);
+                            parent[v] = Some(arc);
+                            relaxed = true;
+                        }
+                    }
+                }
+                if !relaxed {
+                    break;
+                }
+            }
+            let Some(path_cost) = dist[t] else {
+                break;
+            };
+
+            let path = residual.path_to(&parent, t);
+            let flow = residual.bottleneck(&path);
+            for &arc in path.iter() {
+                residual.push(arc, flow);
+            }
+            value = value + flow;
+            cost = cost + path_cost * flow;
+        }
+
+        Ok(MinCostFlow {
+            value,
+            cost,
+            flows: residual.flows(),
+        })
+    }
+}
@@ -0,0 +1,365 @@
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
+use super::Graph;
+
+/// true if there's a bijection between the nodes of `a` and `b` that keeps every
+/// edge and non-edge, values and weights aren't compared.
+pub fn is_isomorphic<K1, T1, W1, S1, K2, T2, W2, S2>(
+    a: &Graph<K1, T1, W1, S1>,
+    b: &Graph<K2, T2, W2, S2>,
+) -> bool
+where
+    K1: Hash + Eq + Clone,
+    K2: Hash + Eq + Clone,
+    S1: BuildHasher + Default,
+    S2: BuildHasher + Default,
+{
+    is_isomorphic_matching(a, b, |_, _| true, |_, _| true)
+}
+
+/// `is_isomorphic` where mapped nodes must satisfy `node_eq` and mapped edges `edge_eq`.
+pub fn is_isomorphic_matching<K1, T1, W1, S1, K2, T2, W2, S2, N, E>(
+    a: &Graph<K1, T1, W1, S1>,
+    b: &Graph<K2, T2, W2, S2>,
+    node_eq: N,
+    edge_eq: E,
+) -> bool
+where
+    K1: Hash + Eq + Clone,
+    K2: Hash + Eq + Clone,
+    S1: BuildHasher + Default,
+    S2: BuildHasher + Default,
+    N: FnMut(&T1, &T2) -> bool,
+    E: FnMut(&W1, &W2) -> bool,
+{
+    if a.nodes_len() != b.nodes_len() || a.edges_len() != b.edges_len() {
+        return false;
+    }
+    Vf2::new(a, b, true, node_eq, edge_eq).next().is_some()
+}
+
+/// VF2, every mapping of the nodes of `pattern` to different nodes of `target`
+/// such that two pattern nodes have an edge exactly when their images do. that's
+/// an isomorphism between `pattern` and an induced subgraph of `target`.
+pub fn subgraph_isomorphisms<'a, K1, T1, W1, S1, K2, T2, W2, S2>(
+    pattern: &'a Graph<K1, T1, W1, S1>,
+    target: &'a Graph<K2, T2, W2, S2>,
+) -> impl Iterator<Item = HashMap<&'a K1, &'a K2>> + 'a
+where
+    K1: Hash + Eq + Clone,
+    K2: Hash + Eq + Clone,
+    S1: BuildHasher + Default,
+    S2: BuildHasher + Default,
+{
+    Vf2::new(pattern, target, false, |_, _| true, |_, _| true)
+}
+
+/// `subgraph_isomorphisms` where mapped nodes must satisfy `node_eq` and mapped
+/// edges `edge_eq`.
+pub fn subgraph_isomorphisms_matching<'a, K1, T1, W1, S1, K2, T2, W2, S2, N, E>(
+    pattern: &'a Graph<K1, T1, W1, S1>,
+    target: &'a Graph<K2, T2, W2, S2>,
+    node_eq: N,
+    edge_eq: E,
+) -> impl Iterator<Item = HashMap<&'a K1, &'a K2>> + 'a
+where
+    K1: Hash + Eq + Clone,
+    K2: Hash + Eq + Clone,
+    S1: BuildHasher + Default,
+    S2: BuildHasher + Default,
+    N: FnMut(&T1, &T2) -> bool + 'a,
+    E: FnMut(&W1, &W2) -> bool + 'a,
+{
+    Vf2::new(pattern, target, false, node_eq, edge_eq)
+}
+
+/// one of the two graphs with nodes numbered in iteration order.
+struct Side<'a, K, T, W> {
+    keys: Vec<&'a K>,
+    vals: Vec<&'a T>,
+    succ: Vec<HashMap<usize, &'a W>>,
+    pred: Vec<Vec<usize>>,
+    /// node of the other graph every node is mapped to.
+    core: Vec<Option<usize>>,
+    /// depth at which every node joined the successors or predecessors of the mapped
+    /// nodes, 0 if it hasn't. mapped nodes keep theirs.
+    out: Vec<usize>,
+    inn: Vec<usize>,
+}
+
+impl<'a, K, T, W> Side<'a, K, T, W>
+where
+    K: Hash + Eq + Clone,
+{
+    fn new<S>(graph: &'a Graph<K, T, W, S>) -> Self
+    where
+        S: BuildHasher + Default,
+    {
+        let keys: Vec<&K> = graph.iter().map(|(k, _)| k).collect();
+        let index: HashMap<&K, usize> = keys.iter().enumerate().map(|(i, k)| (*k, i)).collect();
+        let n = keys.len();
+        let mut succ: Vec<HashMap<usize, &W>> = vec![HashMap::new(); n];
+        let mut pred: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for (i, key) in keys.iter().enumerate() {
+            for (to, weight) in graph.out_neighbors(key).unwrap() {
+                if let Some(&j) = index.get(to) {
+                    succ[i].insert(j, weight);
+                    pred[j].push(i);
+                }
+            }
+        }
+        Self {
+            vals: keys.iter().map(|k| &graph.map[*k].val).collect(),
+            keys,
+            succ,
+            pred,
+            core: vec![None; n],
+            out: vec![0; n],
+            inn: vec![0; n],
+        }
+    }
+
+    fn map(&mut self, node: usize, other: usize, depth: usize) {
+        self.core[node] = Some(other);
+        let mark = |set: &mut Vec<usize>, i: usize| {
+            if set[i] == 0 {
+                set[i] = depth;
+            }
+        };
+        mark(&mut self.out, node);
+        mark(&mut self.inn, node);
+        for &succ in self.succ[node].keys() {
+            mark(&mut self.out, succ);
+        }
+        for &pred in self.pred[node].iter() {
+            mark(&mut self.inn, pred);
+        }
+    }
+
+    fn unmap(&mut self, node: usize, depth: usize) {
+        self.core[node] = None;
+        let unmark = |set: &mut Vec<usize>, i: usize| {
+            if set[i] == depth {
+                set[i] = 0;
+            }
+        };
+        unmark(&mut self.out, node);
+        unmark(&mut self.inn, node);
+        for &succ in self.succ[node].keys() {
+            unmark(&mut self.out, succ);
+        }
+        for &pred in self.pred[node].iter() {
+            unmark(&mut self.inn, pred);
+        }
+    }
+
+    /// unmapped nodes in `set`, in increasing order.
+    fn terminal(&self, set: &[usize]) -> Vec<usize> {
+        (0..set.len())
+            .filter(|&i| set[i] != 0 && self.core[i].is_none())
+            .collect()
+    }
+
+    /// number of unmapped neighbors of `node` that are in the out set, the in set and
+    /// neither, counted separately for successors and predecessors.
+    fn look_ahead(&self, node: usize) -> [usize; 6] {
+        let mut ret = [0; 6];
+        let neibs = self.succ[node]
+            .keys()
+            .map(|&i| (0, i))
+            .chain(self.pred[node].iter().map(|&i| (3, i)));
+        for (offset, i) in neibs {
+            if self.core[i].is_some() {
+                continue;
+            }
+            ret[offset] += (self.out[i] != 0) as usize;
+            ret[offset + 1] += (self.inn[i] != 0) as usize;
+            ret[offset + 2] += (self.out[i] == 0 && self.inn[i] == 0) as usize;
+        }
+        ret
+    }
+}
+
+/// pattern node matched at one depth of the search with the target nodes left to try.
+struct Frame {
+    node: usize,
+    candidates: Vec<usize>,
+    next: usize,
+    mapped: Option<usize>,
+}
+
+struct Vf2<'a, K1, T1, W1, K2, T2, W2, N, E> {
+    pattern: Side<'a, K1, T1, W1>,
+    target: Side<'a, K2, T2, W2>,
+    /// whole graph isomorphism instead of induced subgraph isomorphism.
+    exact: bool,
+    node_eq: N,
+    edge_eq: E,
+    stack: Vec<Frame>,
+    started: bool,
+}
+
+impl<'a, K1, T1, W1, K2, T2, W2, N, E> Vf2<'a, K1, T1, W1, K2, T2, W2, N, E>
+where
+    K1: Hash + Eq + Clone,
+    K2: Hash + Eq + Clone,
+    N: FnMut(&T1, &T2) -> bool,
+    E: FnMut(&W1, &W2) -> bool,
+{
+    fn new<S1, S2>(
+        pattern: &'a Graph<K1, T1, W1, S1>,
+        target: &'a Graph<K2, T2, W2, S2>,
+        exact: bool,
+        node_eq: N,
+        edge_eq: E,
+    ) -> Self
+    where
+        S1: BuildHasher + Default,
+        S2: BuildHasher + Default,
+    {
+        Self {
+            pattern: Side::new(pattern),
+            target: Side::new(target),
+            exact,
+            node_eq,
+            edge_eq,
+            stack: Vec::new(),
+            started: false,
+        }
+    }
+
+    /// next pattern node to match and the target nodes it can be matched with. a
+    /// pattern node next to the mapped ones must be matched with a target node next
+    /// to their images in the same direction.
+    fn frame(&self) -> Frame {
+        let (p, t) = (&self.pattern, &self.target);
+        let (node, candidates) = match (p.terminal(&p.out), p.terminal(&p.inn)) {
+            (out, _) if !out.is_empty() => (out[0], t.terminal(&t.out)),
+            (_, inn) if !inn.is_empty() => (inn[0], t.terminal(&t.inn)),
+            _ => {
+                let node = (0..p.core.len()).find(|&i| p.core[i].is_none()).unwrap();
+                let all = (0..t.core.len()).filter(|&i| t.core[i].is_none()).collect();
+                (node, all)
+            }
+        };
+        Frame {
+            node,
+            candidates,
+            next: 0,
+            mapped: None,
+        }
+    }
+
+    fn feasible(&mut self, pn: usize, tn: usize) -> bool {
+        let (p, t) = (&self.pattern, &self.target);
+        if !(self.node_eq)(p.vals[pn], t.vals[tn]) {
+            return false;
+        }
+        if p.succ[pn].contains_key(&pn) != t.succ[tn].contains_key(&tn) {
+            return false;
+        }
+        if let (Some(pw), Some(tw)) = (p.succ[pn].get(&pn), t.succ[tn].get(&tn)) {
+            if !(self.edge_eq)(pw, tw) {
+                return false;
+            }
+        }
+
+        // edges to and from mapped nodes must have images, and their images preimages
+        for (&p2, pw) in p.succ[pn].iter() {
+            let Some(t2) = p.core[p2] else {
+                continue;
+            };
+            match t.succ[tn].get(&t2) {
+                Some(tw) if (self.edge_eq)(pw, tw) => {}
+                _ => return false,
+            }
+        }
+        for &p2 in p.pred[pn].iter() {
+            let Some(t2) = p.core[p2] else {
+                continue;
+            };
+            match t.succ[t2].get(&tn) {
+                Some(tw) if (self.edge_eq)(p.succ[p2][&pn], tw) => {}
+                _ => return false,
+            }
+        }
+        for &t2 in t.succ[tn].keys() {
+            if let Some(p2) = t.core[t2] {
+                if !p.succ[pn].contains_key(&p2) {
+                    return false;
+                }
+            }
+        }
+        for &t2 in t.pred[tn].iter() {
+            if let Some(p2) = t.core[t2] {
+                if !p.succ[p2].contains_key(&pn) {
+                    return false;
+                }
+            }
+        }
+
+        let (pc, tc) = (p.look_ahead(pn), t.look_ahead(tn));
+        match self.exact {
+            true => pc == tc,
+            false => pc.iter().zip(tc.iter()).all(|(a, b)| a <= b),
+        }
+    }
+}
+
+impl<'a, K1, T1, W1, K2, T2, W2, N, E> Iterator for Vf2<'a, K1, T1, W1, K2, T2, W2, N, E>
+where
+    K1: Hash + Eq + Clone,
+    K2: Hash + Eq + Clone,
+    N: FnMut(&T1, &T2) -> bool,
+    E: FnMut(&W1, &W2) -> bool,
+{
+    type Item = HashMap<&'a K1, &'a K2>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            match self.pattern.keys.len() {
+                0 => return Some(HashMap::new()),
+                n if n > self.target.keys.len() => return None,
+                _ => self.stack.push(self.frame()),
+            }
+        }
+        loop {
+            let depth = self.stack.len();
+            let frame = self.stack.last_mut()?;
+            let pn = frame.node;
+            if let Some(tn) = frame.mapped.take() {
+                self.pattern.unmap(pn, depth);
+                self.target.unmap(tn, depth);
+            }
+
+            let frame = self.stack.last_mut().unwrap();
+            let Some(&tn) = frame.candidates.get(frame.next) else {
+                self.stack.pop();
+                continue;
+            };
+            frame.next += 1;
+            if !self.feasible(pn, tn) {
+                continue;
+            }
+            self.stack.last_mut().unwrap().mapped = Some(tn);
+            self.pattern.map(pn, tn, depth);
+            self.target.map(tn, pn, depth);
+
+            if depth == self.pattern.keys.len() {
+                let mapping = self
+                    .pattern
+                    .core
+                    .iter()
+                    .enumerate()
+                    .map(|(p, t)| (self.pattern.keys[p], self.target.keys[t.unwrap()]));
+                return Some(mapping.collect());
+            }
+            let frame = self.frame();
+            self.stack.push(frame);
+        }
+    }
+}
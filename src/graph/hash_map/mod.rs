@@ -0,0 +1,988 @@
+mod bipartite;
+mod centrality;
+mod connectivity;
+mod dominators;
+mod flow;
+mod isomorphism;
+mod mst;
+pub mod multi;
+mod ordered;
+mod paths;
+mod reach;
+mod scc;
+mod transform;
+pub mod undirected;
+
+pub use dominators::Dominators;
+pub use flow::{MaxFlow, MinCostFlow};
+pub use isomorphism::{
+    is_isomorphic, is_isomorphic_matching, subgraph_isomorphisms, subgraph_isomorphisms_matching,
+};
+pub use reach::Reachability;
+
+use ordered::OrderedMap;
+
+use super::algo;
+
+use std::{
+    cmp::Ordering,
+    collections::{
+        hash_map::{self, RandomState},
+        HashMap, HashSet, VecDeque,
+    },
+    fmt::{Debug, Display, Formatter},
+    hash::{BuildHasher, Hash},
+    vec,
+};
+
+/// nodes in the order they were inserted with the neighbors of every node in the
+/// order their edges were inserted.
+type Order<K, S> = OrderedMap<K, OrderedMap<K, (), S>, S>;
+
+/// directed graph on `HashMap`s hashed with `S`. iteration order is the one of the
+/// maps unless insertion order is enabled, a hasher without random state like
+/// `BuildHasherDefault<DefaultHasher>` makes it the same on every run.
+#[derive(Debug)]
+pub struct Graph<K, T, W, S = RandomState> {
+    map: HashMap<K, Node<K, T, W, S>, S>,
+    /// optional reverse adjacency, `rev[to]` holds every `from` with an edge `from -> to`.
+    rev: Option<HashMap<K, HashSet<K, S>, S>>,
+    /// optional insertion order followed by iteration, traversals and `Display`.
+    order: Option<Order<K, S>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Node<K, T, W, S = RandomState> {
+    key: K,
+    val: T,
+    neibs: HashMap<K, W, S>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Edge<'a, K, W> {
+    pub from: &'a K,
+    pub to: &'a K,
+    pub weight: &'a W,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum EdgeErr {
+    FromNone,
+    ToNone,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum IntegrityErr<'a, K> {
+    /// edge to a node that isn't in the graph.
+    DanglingEdge { from: &'a K, to: &'a K },
+    /// edge missing from the reverse index.
+    MissingReverse { from: &'a K, to: &'a K },
+    /// reverse index entry of a node that isn't in the graph.
+    DanglingReverse { from: &'a K, to: &'a K },
+}
+
+impl<K, T, W> Graph<K, T, W>
+where
+    K: Hash + Eq + Clone,
+{
+    /// graph with the default hasher, `Graph::default()` makes one with any other.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// graph that keeps a reverse adjacency index, making `remove`, `in_neighbors`
+    /// and `in_degree` proportional to the number of incoming edges.
+    pub fn with_reverse_index() -> Self {
+        let mut graph = Self::new();
+        graph.enable_reverse_index();
+        graph
+    }
+
+    /// graph that iterates its nodes and edges in the order they were inserted.
+    pub fn with_insertion_order() -> Self {
+        let mut graph = Self::new();
+        graph.enable_insertion_order();
+        graph
+    }
+}
+
+impl<K, T, W, S> Default for Graph<K, T, W, S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self {
+            map: HashMap::default(),
+            rev: None,
+            order: None,
+        }
+    }
+}
+
+impl<K, T, W, S> Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    pub fn has_reverse_index(&self) -> bool {
+        self.rev.is_some()
+    }
+
+    /// builds the reverse adjacency index from the current edges.
+    pub fn enable_reverse_index(&mut self) {
+        let mut rev: HashMap<K, HashSet<K, S>, S> = HashMap::default();
+        for (key, node) in self.map.iter() {
+            for neib in node.neibs.keys() {
+                rev.entry(neib.clone()).or_default().insert(key.clone());
+            }
+        }
+        self.rev = Some(rev);
+    }
+
+    pub fn disable_reverse_index(&mut self) {
+        self.rev = None;
+    }
+
+    pub fn has_insertion_order(&self) -> bool {
+        self.order.is_some()
+    }
+
+    /// makes iteration, `edges`, traversals and `Display` follow the order nodes and
+    /// edges are inserted in, like `IndexMap`. the current nodes and edges keep their
+    /// current iteration order and go first. replacing a node or the weight of an edge
+    /// keeps its position.
+    pub fn enable_insertion_order(&mut self) {
+        let mut order: Order<K, S> = OrderedMap::new();
+        for (key, node) in self.map.iter() {
+            order.insert(key.clone(), neighbor_order(node));
+        }
+        self.order = Some(order);
+    }
+
+    pub fn disable_insertion_order(&mut self) {
+        self.order = None;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.len() == 0
+    }
+
+    pub fn get(&self, node_key: &K) -> Option<&Node<K, T, W, S>> {
+        self.map.get(node_key)
+    }
+
+    pub fn get_mut(&mut self, node_key: &K) -> Option<&mut Node<K, T, W, S>> {
+        self.map.get_mut(node_key)
+    }
+
+    pub fn contains(&self, node_key: &K) -> bool {
+        self.map.contains_key(node_key)
+    }
+
+    /// # Returns
+    /// returns old value if map has the node.key
+    /// returns None if map doesn't have the node.key
+    pub fn insert(&mut self, node: Node<K, T, W, S>) -> Option<Node<K, T, W, S>> {
+        if let Some(rev) = self.rev.as_mut() {
+            if let Some(old) = self.map.get(&node.key) {
+                for neib in old.neibs.keys() {
+                    if let Some(froms) = rev.get_mut(neib) {
+                        froms.remove(&node.key);
+                    }
+                }
+            }
+            for neib in node.neibs.keys() {
+                rev.entry(neib.clone())
+                    .or_default()
+                    .insert(node.key.clone());
+            }
+        }
+        if let Some(order) = self.order.as_mut() {
+            order.insert(node.key.clone(), neighbor_order(&node));
+        }
+        self.map.insert(node.key.clone(), node)
+    }
+
+    pub fn insert_node(&mut self, key: K, val: T) {
+        self.insert(Node::with_hasher(key, val, S::default()));
+    }
+
+    /// removes the node, its edges and every edge pointing to it.
+    pub fn remove(&mut self, node_key: K) -> Option<Node<K, T, W, S>> {
+        let node = self.map.remove(&node_key)?;
+        let mut order = self.order.as_mut();
+        if let Some(order) = order.as_mut() {
+            order.remove(&node_key);
+        }
+        match self.rev.as_mut() {
+            Some(rev) => {
+                for neib in node.neibs.keys() {
+                    if let Some(froms) = rev.get_mut(neib) {
+                        froms.remove(&node_key);
+                    }
+                }
+                for from in rev.remove(&node_key).into_iter().flatten() {
+                    if let Some(order) = order.as_mut().and_then(|o| o.get_mut(&from)) {
+                        order.remove(&node_key);
+                    }
+                    if let Some(from) = self.map.get_mut(&from) {
+                        from.neibs.remove(&node_key);
+                    }
+                }
+            }
+            None => {
+                for (_, from) in self.map.iter_mut() {
+                    from.neibs.remove(&node_key);
+                }
+                for neibs in order.into_iter().flat_map(|o| o.values_mut()) {
+                    neibs.remove(&node_key);
+                }
+            }
+        }
+        Some(node)
+    }
+
+    pub fn nodes(&self) -> Vec<&Node<K, T, W, S>> {
+        self.iter().map(|(_, node)| node).collect()
+    }
+
+    pub fn nodes_len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn edges(&self) -> Vec<Edge<K, W>> {
+        let mut ret: Vec<Edge<K, W>> = Vec::new();
+        for (from, node) in self.iter() {
+            for (to, weight) in self.neighbors_of(node) {
+                ret.push(Edge { from, to, weight })
+            }
+        }
+        ret
+    }
+
+    pub fn edges_len(&self) -> usize {
+        let mut ret = 0;
+        for i in self.map.iter() {
+            ret += i.1.neibs.len();
+        }
+        ret
+    }
+
+    /// # Error
+    /// if graph doesn't contain to_node_key returns `Err(EdgeErr::ToNone)`.
+    /// if graph doesn't contain from_node_key returns `Err(EdgeErr::FromNone)`.
+    pub fn insert_edge(
+        &mut self,
+        from_node_key: K,
+        to_node_key: K,
+        weight: W,
+    ) -> Result<(), EdgeErr> {
+        if self.map.contains_key(&to_node_key) {
+            if let Some(n1) = self.map.get_mut(&from_node_key) {
+                if let Some(neibs) = self.order.as_mut().and_then(|o| o.get_mut(&from_node_key)) {
+                    neibs.insert(to_node_key.clone(), ());
+                }
+                if let Some(rev) = self.rev.as_mut() {
+                    rev.entry(to_node_key.clone())
+                        .or_default()
+                        .insert(from_node_key);
+                }
+                n1.neibs.insert(to_node_key, weight);
+                Ok(())
+            } else {
+                Err(EdgeErr::FromNone)
+            }
+        } else {
+            Err(EdgeErr::ToNone)
+        }
+    }
+
+    /// # Returns
+    /// if `from_node_key`'s `Node` contains neighbor `to_node_key` returns old weight.
+    ///
+    /// # Error
+    /// if graph doesn't contain `to_node_key` returns `Err(EdgeErr::ToNone)`.
+    /// if graph doesn't contain `from_node_key` returns `Err(EdgeErr::FromNone)`.
+    pub fn remove_edge(&mut self, from_node_key: K, to_node_key: K) -> Result<Option<W>, EdgeErr> {
+        if self.map.contains_key(&to_node_key) {
+            if let Some(n1) = self.map.get_mut(&from_node_key) {
+                if let Some(froms) = self.rev.as_mut().and_then(|rev| rev.get_mut(&to_node_key)) {
+                    froms.remove(&from_node_key);
+                }
+                if let Some(neibs) = self.order.as_mut().and_then(|o| o.get_mut(&from_node_key)) {
+                    neibs.remove(&to_node_key);
+                }
+                Ok(n1.remove_neighbor(to_node_key))
+            } else {
+                Err(EdgeErr::FromNone)
+            }
+        } else {
+            Err(EdgeErr::ToNone)
+        }
+    }
+
+    /// # Error
+    /// if graph doesn't contain `from_node_key`'s Node, returns `Err(EdgeErr::FromNone)`.
+    /// if `from_node_key`'s Node doesn't contain `to_node_key` neighbor, returns `Err(EdgeErr::ToNone)`.
+    pub fn get_weight(&self, from_node_key: K, to_node_key: K) -> Result<&W, EdgeErr> {
+        if let Some(n1) = self.map.get(&from_node_key) {
+            match n1.neibs.get(&to_node_key) {
+                Some(w) => Ok(w),
+                None => Err(EdgeErr::ToNone),
+            }
+        } else {
+            Err(EdgeErr::FromNone)
+        }
+    }
+
+    /// # Error
+    /// if graph doesn't contain `from_node_key`'s Node, returns `Err(EdgeErr::FromNone)`.
+    /// if `from_node_key`'s Node doesn't contain `to_node_key` neighbor, returns `Err(EdgeErr::ToNone)`.
+    pub fn get_weight_mut(&mut self, from_node_key: K, to_node_key: K) -> Result<&mut W, EdgeErr> {
+        if let Some(n1) = self.map.get_mut(&from_node_key) {
+            match n1.neibs.get_mut(&to_node_key) {
+                Some(w) => Ok(w),
+                None => Err(EdgeErr::ToNone),
+            }
+        } else {
+            Err(EdgeErr::FromNone)
+        }
+    }
+
+    pub fn iter<'a>(&'a self) -> Iter<'a, K, T, W, S> {
+        let inner = match self.order.as_ref() {
+            Some(order) => IterInner::Ordered {
+                keys: order.keys(),
+                map: &self.map,
+            },
+            None => IterInner::Map(self.map.iter()),
+        };
+        Iter { inner }
+    }
+
+    pub fn iter_mut<'a>(&'a mut self) -> IterMut<'a, K, T, W, S> {
+        let inner = match self.order.as_ref() {
+            Some(order) => {
+                let mut nodes: Vec<_> = self.map.iter_mut().collect();
+                nodes.sort_by_key(|(k, _)| order.position(k));
+                IterMutInner::Ordered(nodes.into_iter())
+            }
+            None => IterMutInner::Map(self.map.iter_mut()),
+        };
+        IterMut { inner }
+    }
+
+    /// # Returns
+    /// keys and weights of the edges out of `node_key`, in insertion order if it's
+    /// enabled. None if graph doesn't contain `node_key`.
+    pub fn out_neighbors(&self, node_key: &K) -> Option<OutNeighbors<'_, K, W, S>> {
+        self.map.get(node_key).map(|node| self.neighbors_of(node))
+    }
+
+    fn neighbors_of<'a>(&'a self, node: &'a Node<K, T, W, S>) -> OutNeighbors<'a, K, W, S> {
+        let inner = match self.order.as_ref().and_then(|o| o.get(&node.key)) {
+            Some(order) => IterInner::Ordered {
+                keys: order.keys(),
+                map: &node.neibs,
+            },
+            None => IterInner::Map(node.neibs.iter()),
+        };
+        OutNeighbors { inner }
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        if let Some(rev) = self.rev.as_mut() {
+            rev.clear();
+        }
+        if let Some(order) = self.order.as_mut() {
+            order.clear();
+        }
+    }
+
+    /// # Returns
+    /// keys of every node with an edge to `node_key`, None if graph doesn't contain `node_key`.
+    pub fn in_neighbors(&self, node_key: &K) -> Option<Vec<&K>> {
+        if !self.map.contains_key(node_key) {
+            return None;
+        }
+        let ret = match self.rev.as_ref() {
+            Some(rev) => rev.get(node_key).into_iter().flatten().collect(),
+            None => self
+                .iter()
+                .filter(|(_, from)| from.neibs.contains_key(node_key))
+                .map(|(key, _)| key)
+                .collect(),
+        };
+        Some(ret)
+    }
+
+    /// every node with the nodes it shares an edge with in either direction, each
+    /// listed once in iteration order. edges to keys that aren't in the graph are skipped.
+    fn undirected_neighbors(&self) -> HashMap<&K, Vec<&K>> {
+        let mut adj: HashMap<&K, Vec<&K>> = self.iter().map(|(k, _)| (k, Vec::new())).collect();
+        let mut seen: HashSet<(&K, &K)> = HashSet::new();
+        for (key, node) in self.iter() {
+            for (neib, _) in self.neighbors_of(node) {
+                if let Some((neib, _)) = self.map.get_key_value(neib) {
+                    if seen.insert((key, neib)) {
+                        adj.get_mut(key).unwrap().push(neib);
+                    }
+                    if seen.insert((neib, key)) {
+                        adj.get_mut(neib).unwrap().push(key);
+                    }
+                }
+            }
+        }
+        adj
+    }
+
+    /// # Returns
+    /// number of edges to `node_key`, None if graph doesn't contain `node_key`.
+    pub fn in_degree(&self, node_key: &K) -> Option<usize> {
+        self.in_neighbors(node_key).map(|i| i.len())
+    }
+
+    /// checks that every edge points to a node in the graph and that the reverse
+    /// index, if there's one, agrees with the edges.
+    ///
+    /// # Error
+    /// every inconsistency found.
+    pub fn validate(&self) -> Result<(), Vec<IntegrityErr<'_, K>>> {
+        let mut errs = Vec::new();
+        for (from, node) in self.map.iter() {
+            for to in node.neibs.keys() {
+                if !self.map.contains_key(to) {
+                    errs.push(IntegrityErr::DanglingEdge { from, to });
+                }
+                if let Some(rev) = self.rev.as_ref() {
+                    if !rev.get(to).is_some_and(|froms| froms.contains(from)) {
+                        errs.push(IntegrityErr::MissingReverse { from, to });
+                    }
+                }
+            }
+        }
+        for (to, froms) in self.rev.iter().flatten() {
+            for from in froms.iter() {
+                if !self.map.contains_key(from) {
+                    errs.push(IntegrityErr::DanglingReverse { from, to });
+                }
+            }
+        }
+        if errs.is_empty() {
+            Ok(())
+        } else {
+            Err(errs)
+        }
+    }
+
+    pub fn dfs_iter<'a>(&'a self, start_node_key: &'a K) -> DfsIter<'a, K, T, W, S> {
+        DfsIter::new(self, start_node_key)
+    }
+
+    pub fn bfs_iter<'a>(&'a self, start_node_key: &'a K) -> BfsIter<'a, K, T, W, S> {
+        BfsIter::new(self, start_node_key)
+    }
+
+    /// # Returns
+    /// nodes of a path using every edge exactly once, a single node if the graph has no edges.
+    /// None if the graph is empty or has no Eulerian path.
+    pub fn find_eulerian_path(&self) -> Option<Vec<&K>> {
+        algo::eulerian_path(self)
+    }
+
+    pub fn has_eulerian_path(&self) -> bool {
+        algo::has_eulerian_path(self)
+    }
+
+    /// # Returns
+    /// nodes of a closed path using every edge exactly once, the first node is repeated at the end.
+    /// None if the graph is empty or has no Eulerian circuit.
+    pub fn find_eulerian_circuit(&self) -> Option<Vec<&K>> {
+        algo::eulerian_circuit(self)
+    }
+
+    pub fn has_eulerian_circuit(&self) -> bool {
+        self.find_eulerian_circuit().is_some()
+    }
+
+    /// bitmask dynamic programming, see `algo::hamiltonian_path`.
+    ///
+    /// # Returns
+    /// nodes of a path visiting every node once.
+    ///
+    /// # Panics
+    /// if the graph has more than `algo::HAMILTONIAN_MAX_NODES` nodes
+    pub fn find_hamiltonian_path(&self) -> Option<Vec<&K>> {
+        algo::hamiltonian_path(self)
+    }
+
+    /// bitmask dynamic programming, see `algo::hamiltonian_cycle`.
+    ///
+    /// # Returns
+    /// nodes of a cycle visiting every node once, the last one has an edge to the first.
+    ///
+    /// # Panics
+    /// if the graph has more than `algo::HAMILTONIAN_MAX_NODES` nodes
+    pub fn find_hamiltonian_cycle(&self) -> Option<Vec<&K>> {
+        algo::hamiltonian_cycle(self)
+    }
+}
+
+pub(crate) struct DijkstraPair<K, W>(pub(crate) K, pub(crate) W);
+
+impl<K, W> PartialEq for DijkstraPair<K, W>
+where
+    W: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.1 == other.1
+    }
+}
+impl<K, W> PartialOrd for DijkstraPair<K, W>
+where
+    W: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.1.partial_cmp(&other.1)
+    }
+}
+impl<K, W> Eq for DijkstraPair<K, W> where W: Eq {}
+impl<K, W> Ord for DijkstraPair<K, W>
+where
+    W: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.1.cmp(&other.1)
+    }
+}
+
+impl<K, T, S> Graph<K, T, usize, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    pub fn dijkstra_shortest_path<'a>(
+        &'a self,
+        start_node_key: &'a K,
+        dest_node_key: &'a K,
+    ) -> Option<Vec<&'a K>> {
+        algo::dijkstra_shortest_path(self, start_node_key, dest_node_key).map(|(path, _)| path)
+    }
+
+    /// # Returns
+    /// distance from `start_node_key` to every node, usize::MAX if unreachable.
+    pub fn dijkstra_shortest_dist(&self, start_node_key: &K) -> Vec<(&K, usize)> {
+        let dist = match self.map.get_key_value(start_node_key) {
+            Some((start, _)) => algo::dijkstra(self, start),
+            None => HashMap::new(),
+        };
+        self.iter()
+            .map(|(k, _)| (k, dist.get(k).copied().unwrap_or(usize::MAX)))
+            .collect()
+    }
+}
+
+impl<K, T, W> Node<K, T, W>
+where
+    K: Hash + Eq + Clone,
+{
+    pub fn new(key: K, val: T) -> Self {
+        Self::with_hasher(key, val, RandomState::new())
+    }
+}
+
+impl<K, T, W, S> Node<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    /// node whose neighbors are hashed with `hasher`.
+    pub fn with_hasher(key: K, val: T, hasher: S) -> Self {
+        Self {
+            key,
+            val,
+            neibs: HashMap::with_hasher(hasher),
+        }
+    }
+
+    pub const fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub const fn val(&self) -> &T {
+        &self.val
+    }
+
+    pub fn val_mut(&mut self) -> &mut T {
+        &mut self.val
+    }
+
+    pub const fn neighbors(&self) -> &HashMap<K, W, S> {
+        &self.neibs
+    }
+
+    pub fn neighbors_as_vec(&self) -> Vec<(&K, &W)> {
+        self.neibs.iter().collect()
+    }
+
+    /// edges are removed through `Graph::remove_edge` which keeps its indexes in sync.
+    pub(super) fn remove_neighbor(&mut self, neib_key: K) -> Option<W> {
+        self.neibs.remove(&neib_key)
+    }
+}
+
+/// neighbors of `node` in the order of its map.
+fn neighbor_order<K, T, W, S>(node: &Node<K, T, W, S>) -> OrderedMap<K, (), S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    let mut order = OrderedMap::new();
+    for neib in node.neibs.keys() {
+        order.insert(neib.clone(), ());
+    }
+    order
+}
+
+impl<K, T, W, S> PartialEq for Node<K, T, W, S>
+where
+    K: PartialEq + Hash + Eq + Clone,
+    T: PartialEq,
+    W: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.val == other.val && self.neighbors() == other.neighbors()
+    }
+}
+
+impl<'a, K, W> Edge<'a, K, W> {
+    pub fn new(from: &'a K, to: &'a K, weight: &'a W) -> Self {
+        Edge { from, to, weight }
+    }
+}
+
+pub struct DfsIter<'a, K, T, W, S = RandomState> {
+    graph: &'a Graph<K, T, W, S>,
+    stack: Vec<&'a Node<K, T, W, S>>,
+    visited: HashSet<&'a K>,
+}
+
+impl<'a, K, T, W, S> DfsIter<'a, K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    /// # Panics
+    /// if graph doesn't contain `start_node`.
+    pub fn new(graph: &'a Graph<K, T, W, S>, start_node_key: &'a K) -> Self {
+        let mut visited = HashSet::new();
+        visited.insert(start_node_key);
+        let start_node = graph.get(start_node_key).unwrap();
+        let mut stack = Vec::new();
+        stack.push(start_node);
+
+        Self {
+            graph,
+            stack,
+            visited,
+        }
+    }
+}
+
+impl<'a, K, T, W, S> Iterator for DfsIter<'a, K, T, W, S>
+where
+    K: Eq + Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    type Item = &'a Node<K, T, W, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current_node = self.stack.pop()?;
+        for (neighbour_key, _) in self.graph.neighbors_of(current_node) {
+            if !self.visited.contains(neighbour_key) {
+                let neighbour_node = self.graph.get(neighbour_key).unwrap();
+                self.stack.push(neighbour_node);
+                self.visited.insert(neighbour_key);
+            }
+        }
+        Some(current_node)
+    }
+}
+
+pub struct BfsIter<'a, K, T, W, S = RandomState> {
+    graph: &'a Graph<K, T, W, S>,
+    queue: VecDeque<&'a Node<K, T, W, S>>,
+    visited: HashSet<&'a K>,
+}
+
+impl<'a, K, T, W, S> BfsIter<'a, K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    /// # Panics
+    /// if graph doesn't contain `start_node`.
+    pub fn new(graph: &'a Graph<K, T, W, S>, start_node_key: &'a K) -> Self {
+        let mut visited = HashSet::new();
+        visited.insert(start_node_key);
+        let start_node = graph.get(start_node_key).unwrap();
+        let mut queue = VecDeque::new();
+        queue.push_back(start_node);
+
+        Self {
+            graph,
+            queue,
+            visited,
+        }
+    }
+}
+
+impl<'a, K, T, W, S> Iterator for BfsIter<'a, K, T, W, S>
+where
+    K: Eq + Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    type Item = &'a Node<K, T, W, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current_node = self.queue.pop_front()?;
+        for (neighbour_key, _) in self.graph.neighbors_of(current_node) {
+            if !self.visited.contains(neighbour_key) {
+                let neighbour_node = self.graph.get(neighbour_key).unwrap();
+                self.queue.push_back(neighbour_node);
+                self.visited.insert(neighbour_key);
+            }
+        }
+        Some(current_node)
+    }
+}
+
+/// iteration over a map in its own order or in the order of the keys of an
+/// `OrderedMap` with values `O`.
+enum IterInner<'a, K, V, S, M, O> {
+    Map(M),
+    Ordered {
+        keys: ordered::Keys<'a, K, O>,
+        map: &'a HashMap<K, V, S>,
+    },
+}
+
+impl<'a, K, V, S, M, O> Iterator for IterInner<'a, K, V, S, M, O>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+    M: Iterator<Item = (&'a K, &'a V)>,
+{
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            IterInner::Map(iter) => iter.next(),
+            IterInner::Ordered { keys, map } => keys.find_map(|k| map.get_key_value(k)),
+        }
+    }
+}
+
+type NodesInner<'a, K, T, W, S> = IterInner<
+    'a,
+    K,
+    Node<K, T, W, S>,
+    S,
+    hash_map::Iter<'a, K, Node<K, T, W, S>>,
+    OrderedMap<K, (), S>,
+>;
+
+pub struct Iter<'a, K, T, W, S = RandomState> {
+    inner: NodesInner<'a, K, T, W, S>,
+}
+
+impl<'a, K, T, W, S> Iterator for Iter<'a, K, T, W, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    type Item = (&'a K, &'a Node<K, T, W, S>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+/// keys and weights of the edges out of a node, see `Graph::out_neighbors`.
+pub struct OutNeighbors<'a, K, W, S = RandomState> {
+    inner: IterInner<'a, K, W, S, hash_map::Iter<'a, K, W>, ()>,
+}
+
+impl<'a, K, W, S> Iterator for OutNeighbors<'a, K, W, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    type Item = (&'a K, &'a W);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+type NodeMut<'a, K, T, W, S> = (&'a K, &'a mut Node<K, T, W, S>);
+
+pub struct IterMut<'a, K, T, W, S = RandomState> {
+    inner: IterMutInner<'a, K, T, W, S>,
+}
+
+enum IterMutInner<'a, K, T, W, S> {
+    Map(hash_map::IterMut<'a, K, Node<K, T, W, S>>),
+    /// nodes sorted by insertion order.
+    Ordered(vec::IntoIter<NodeMut<'a, K, T, W, S>>),
+}
+
+impl<'a, K, T, W, S> Iterator for IterMut<'a, K, T, W, S> {
+    type Item = NodeMut<'a, K, T, W, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            IterMutInner::Map(iter) => iter.next(),
+            IterMutInner::Ordered(iter) => iter.next(),
+        }
+    }
+}
+
+/// nodes of a graph by value, in insertion order if it's enabled.
+pub struct IntoIter<K, T, W, S = RandomState> {
+    inner: IntoIterInner<K, T, W, S>,
+}
+
+enum IntoIterInner<K, T, W, S> {
+    Map(hash_map::IntoIter<K, Node<K, T, W, S>>),
+    /// nodes sorted by insertion order.
+    Ordered(vec::IntoIter<(K, Node<K, T, W, S>)>),
+}
+
+impl<K, T, W, S> Iterator for IntoIter<K, T, W, S> {
+    type Item = (K, Node<K, T, W, S>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            IntoIterInner::Map(iter) => iter.next(),
+            IntoIterInner::Ordered(iter) => iter.next(),
+        }
+    }
+}
+
+impl<'a, K, T, W, S> IntoIterator for &'a Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    type Item = (&'a K, &'a Node<K, T, W, S>);
+    type IntoIter = Iter<'a, K, T, W, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, T, W, S> IntoIterator for &'a mut Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    type Item = NodeMut<'a, K, T, W, S>;
+    type IntoIter = IterMut<'a, K, T, W, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, T, W, S> IntoIterator for Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    type Item = (K, Node<K, T, W, S>);
+    type IntoIter = IntoIter<K, T, W, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let inner = match self.order {
+            Some(order) => {
+                let mut nodes: Vec<_> = self.map.into_iter().collect();
+                nodes.sort_by_key(|(k, _)| order.position(k));
+                IntoIterInner::Ordered(nodes.into_iter())
+            }
+            None => IntoIterInner::Map(self.map.into_iter()),
+        };
+        IntoIter { inner }
+    }
+}
+
+impl<K, T, W, S> Display for Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone + Debug,
+    T: Debug,
+    W: Debug + Clone,
+    S: BuildHasher + Default,
+{
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        fmt_graph(f, self.iter(), &self.edges())
+    }
+}
+
+/// `Display` of a graph with the given nodes and edges, shared with `undirected::Graph`.
+fn fmt_graph<'a, K, T, W, S>(
+    f: &mut Formatter,
+    nodes: impl Iterator<Item = (&'a K, &'a Node<K, T, W, S>)>,
+    edges: &[Edge<'_, K, W>],
+) -> std::fmt::Result
+where
+    K: Debug + 'a,
+    T: Debug + 'a,
+    W: Debug + 'a,
+    S: 'a,
+{
+    writeln!(f, "Graph {{")?;
+    writeln!(f, "    nodes: [")?;
+    for (k, v) in nodes {
+        writeln!(f, "        {{ key: {:?}, val: {:?} }},", k, v.val)?;
+    }
+    writeln!(f, "    ]")?;
+    writeln!(f, "\n    edges: [")?;
+    for i in edges.iter() {
+        writeln!(f, "        {:?},", i)?;
+    }
+    writeln!(f, "    ]")?;
+    writeln!(f, "}}")?;
+    Ok(())
+}
+
+impl<K, T, W> Display for Node<K, W, T>
+where
+    K: Debug,
+    T: Debug,
+    W: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Node {{ key: {:?}, val: {:?}, neibs: {:?} }}",
+            self.key, self.val, self.neibs
+        )
+    }
+}
+
+impl<'a, K, W> Display for Edge<'a, K, W>
+where
+    K: Debug,
+    W: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{{ from: {:?}, to: {:?}, weight: {:?} }}",
+            self.from, self.to, self.weight
+        )
+    }
+}
@@ -0,0 +1,86 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    hash::{BuildHasher, Hash},
+    ops::Add,
+};
+
+use super::{DijkstraPair, Edge, Graph};
+use crate::graph::union_find::DisjointSet;
+
+impl<K, T, W, S> Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+    W: Ord + Clone + Default + Add<Output = W>,
+{
+    /// Kruskal's algorithm, every edge is treated as undirected.
+    ///
+    /// # Returns
+    /// edges of a minimum spanning tree for every connected component and their total weight.
+    pub fn minimum_spanning_forest(&self) -> (Vec<Edge<'_, K, W>>, W) {
+        let index: HashMap<&K, usize> = self.iter().enumerate().map(|(i, (k, _))| (k, i)).collect();
+        let mut edges = self.edges();
+        edges.retain(|e| index.contains_key(e.to));
+        edges.sort_by(|a, b| a.weight.cmp(b.weight));
+
+        let mut set = DisjointSet::new(index.len());
+        let mut forest = Vec::new();
+        let mut total = W::default();
+        for edge in edges {
+            if forest.len() + 1 >= index.len() {
+                break;
+            }
+            if set.union(index[edge.from], index[edge.to]) {
+                total = total + edge.weight.clone();
+                forest.push(edge);
+            }
+        }
+        (forest, total)
+    }
+
+    /// Prim's algorithm, every edge is treated as undirected.
+    ///
+    /// # Returns
+    /// edges of a minimum spanning tree for every connected component and their total weight.
+    pub fn minimum_spanning_forest_prim(&self) -> (Vec<Edge<'_, K, W>>, W) {
+        // neighbor through the edge, stored edge
+        let mut adjacent: HashMap<&K, Vec<_>> = HashMap::with_capacity(self.map.len());
+        for edge in self.edges() {
+            if edge.from == edge.to || !self.map.contains_key(edge.to) {
+                continue;
+            }
+            adjacent
+                .entry(edge.to)
+                .or_default()
+                .push((edge.from, Edge::new(edge.from, edge.to, edge.weight)));
+            adjacent.entry(edge.from).or_default().push((edge.to, edge));
+        }
+
+        let mut visited: HashSet<&K> = HashSet::with_capacity(self.map.len());
+        let mut prio = BinaryHeap::new();
+        let mut forest = Vec::new();
+        let mut total = W::default();
+        for (start, _) in self.iter() {
+            if !visited.insert(start) {
+                continue;
+            }
+            for (neib, edge) in adjacent.get(start).into_iter().flatten() {
+                prio.push(Reverse(DijkstraPair((neib, edge), edge.weight)));
+            }
+            while let Some(Reverse(DijkstraPair((key, edge), weight))) = prio.pop() {
+                if !visited.insert(key) {
+                    continue;
+                }
+                total = total + weight.clone();
+                forest.push(Edge::new(edge.from, edge.to, weight));
+                for (neib, edge) in adjacent.get(key).into_iter().flatten() {
+                    if !visited.contains(neib) {
+                        prio.push(Reverse(DijkstraPair((neib, edge), edge.weight)));
+                    }
+                }
+            }
+        }
+        (forest, total)
+    }
+}
@@ -0,0 +1,466 @@
+use std::{
+    cmp::Reverse,
+    collections::{
+        hash_map::{self, RandomState},
+        BinaryHeap, HashMap,
+    },
+    fmt::{Debug, Display, Formatter},
+    hash::{BuildHasher, Hash},
+};
+
+use super::{DijkstraPair, EdgeErr};
+use crate::graph::algo;
+
+/// directed multigraph, any number of parallel edges can connect two nodes
+/// and every edge is addressed by its `EdgeId`.
+#[derive(Debug)]
+pub struct Graph<K, T, W, S = RandomState> {
+    map: HashMap<K, Node<K, T, S>, S>,
+    edges: HashMap<EdgeId, EdgeEntry<K, W>, S>,
+    next_id: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Node<K, T, S = RandomState> {
+    key: K,
+    val: T,
+    outs: HashMap<K, Vec<EdgeId>, S>,
+    ins: HashMap<K, Vec<EdgeId>, S>,
+}
+
+/// stable handle of an edge, never reused by the graph that created it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EdgeId(usize);
+
+#[derive(Debug, Clone)]
+struct EdgeEntry<K, W> {
+    from: K,
+    to: K,
+    weight: W,
+}
+
+struct ShortestPaths<'a, K> {
+    dist: HashMap<&'a K, usize>,
+    /// edge used to reach every node
+    prev: HashMap<&'a K, EdgeId>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Edge<'a, K, W> {
+    pub id: EdgeId,
+    pub from: &'a K,
+    pub to: &'a K,
+    pub weight: &'a W,
+}
+
+impl<K, T, W> Graph<K, T, W>
+where
+    K: Hash + Eq + Clone,
+{
+    /// graph with the default hasher, `Graph::default()` makes one with any other.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<K, T, W, S> Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn get(&self, node_key: &K) -> Option<&Node<K, T, S>> {
+        self.map.get(node_key)
+    }
+
+    pub fn get_mut(&mut self, node_key: &K) -> Option<&mut Node<K, T, S>> {
+        self.map.get_mut(node_key)
+    }
+
+    pub fn contains(&self, node_key: &K) -> bool {
+        self.map.contains_key(node_key)
+    }
+
+    /// # Returns
+    /// returns old value if graph has the node_key, its edges are kept.
+    /// returns None if graph doesn't have the node_key.
+    pub fn insert_node(&mut self, key: K, val: T) -> Option<T> {
+        match self.map.get_mut(&key) {
+            Some(node) => Some(std::mem::replace(&mut node.val, val)),
+            None => {
+                self.map.insert(key.clone(), Node::new(key, val));
+                None
+            }
+        }
+    }
+
+    /// removes the node and every edge touching it.
+    ///
+    /// # Returns
+    /// value of the removed node.
+    pub fn remove(&mut self, node_key: K) -> Option<T> {
+        let node = self.map.remove(&node_key)?;
+        for id in node
+            .outs
+            .into_values()
+            .chain(node.ins.into_values())
+            .flatten()
+        {
+            if let Some(edge) = self.edges.remove(&id) {
+                self.unlink(id, &edge);
+            }
+        }
+        Some(node.val)
+    }
+
+    pub fn nodes(&self) -> Vec<&Node<K, T, S>> {
+        self.map.values().collect()
+    }
+
+    pub fn nodes_len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn edges(&self) -> Vec<Edge<'_, K, W>> {
+        self.edges.iter().map(|(id, e)| e.as_edge(*id)).collect()
+    }
+
+    pub fn edges_len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// # Error
+    /// if graph doesn't contain to_node_key returns `Err(EdgeErr::ToNone)`.
+    /// if graph doesn't contain from_node_key returns `Err(EdgeErr::FromNone)`.
+    pub fn insert_edge(
+        &mut self,
+        from_node_key: K,
+        to_node_key: K,
+        weight: W,
+    ) -> Result<EdgeId, EdgeErr> {
+        if !self.map.contains_key(&to_node_key) {
+            return Err(EdgeErr::ToNone);
+        }
+        if !self.map.contains_key(&from_node_key) {
+            return Err(EdgeErr::FromNone);
+        }
+        let id = EdgeId(self.next_id);
+        self.next_id += 1;
+        self.map
+            .get_mut(&from_node_key)
+            .unwrap()
+            .outs
+            .entry(to_node_key.clone())
+            .or_default()
+            .push(id);
+        self.map
+            .get_mut(&to_node_key)
+            .unwrap()
+            .ins
+            .entry(from_node_key.clone())
+            .or_default()
+            .push(id);
+        self.edges.insert(
+            id,
+            EdgeEntry {
+                from: from_node_key,
+                to: to_node_key,
+                weight,
+            },
+        );
+        Ok(id)
+    }
+
+    /// # Returns
+    /// weight of the removed edge, None if graph doesn't contain `id`.
+    pub fn remove_edge(&mut self, id: EdgeId) -> Option<W> {
+        let edge = self.edges.remove(&id)?;
+        self.unlink(id, &edge);
+        Some(edge.weight)
+    }
+
+    fn unlink(&mut self, id: EdgeId, edge: &EdgeEntry<K, W>) {
+        if let Some(from) = self.map.get_mut(&edge.from) {
+            remove_id(&mut from.outs, &edge.to, id);
+        }
+        if let Some(to) = self.map.get_mut(&edge.to) {
+            remove_id(&mut to.ins, &edge.from, id);
+        }
+    }
+
+    pub fn edge(&self, id: EdgeId) -> Option<Edge<'_, K, W>> {
+        self.edges.get(&id).map(|e| e.as_edge(id))
+    }
+
+    pub fn get_weight(&self, id: EdgeId) -> Option<&W> {
+        self.edges.get(&id).map(|e| &e.weight)
+    }
+
+    pub fn get_weight_mut(&mut self, id: EdgeId) -> Option<&mut W> {
+        self.edges.get_mut(&id).map(|e| &mut e.weight)
+    }
+
+    /// every parallel edge from `from_node_key` to `to_node_key`, in insertion order.
+    ///
+    /// # Error
+    /// if graph doesn't contain `from_node_key`'s Node, returns `Err(EdgeErr::FromNone)`.
+    /// if graph doesn't contain `to_node_key`'s Node, returns `Err(EdgeErr::ToNone)`.
+    pub fn edges_between(
+        &self,
+        from_node_key: &K,
+        to_node_key: &K,
+    ) -> Result<Vec<Edge<'_, K, W>>, EdgeErr> {
+        let from = self.map.get(from_node_key).ok_or(EdgeErr::FromNone)?;
+        if !self.map.contains_key(to_node_key) {
+            return Err(EdgeErr::ToNone);
+        }
+        Ok(from
+            .outs
+            .get(to_node_key)
+            .into_iter()
+            .flatten()
+            .map(|id| self.edges[id].as_edge(*id))
+            .collect())
+    }
+
+    /// # Returns
+    /// the lightest of the parallel edges from `from_node_key` to `to_node_key`.
+    pub fn min_edge(&self, from_node_key: &K, to_node_key: &K) -> Option<Edge<'_, K, W>>
+    where
+        W: Ord,
+    {
+        self.map
+            .get(from_node_key)?
+            .outs
+            .get(to_node_key)?
+            .iter()
+            .map(|id| self.edges[id].as_edge(*id))
+            .min_by(|a, b| a.weight.cmp(b.weight))
+    }
+
+    pub fn iter(&self) -> hash_map::Iter<'_, K, Node<K, T, S>> {
+        self.map.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.edges.clear();
+    }
+
+    /// Hierholzer's algorithm, every parallel edge is used once.
+    ///
+    /// # Returns
+    /// edges of a path using every edge exactly once, empty if the graph has no edges.
+    /// None if the graph is empty or has no Eulerian path.
+    pub fn find_eulerian_path(&self) -> Option<Vec<EdgeId>> {
+        self.eulerian(false)
+    }
+
+    /// # Returns
+    /// edges of a closed path using every edge exactly once, empty if the graph has no edges.
+    /// None if the graph is empty or has no Eulerian circuit.
+    pub fn find_eulerian_circuit(&self) -> Option<Vec<EdgeId>> {
+        self.eulerian(true)
+    }
+
+    fn eulerian(&self, circuit: bool) -> Option<Vec<EdgeId>> {
+        let index: HashMap<&K, usize> = self.map.keys().enumerate().map(|(i, k)| (k, i)).collect();
+        let (ids, edges): (Vec<EdgeId>, Vec<(usize, usize)>) = self
+            .edges
+            .iter()
+            .map(|(id, e)| (*id, (index[&e.from], index[&e.to])))
+            .unzip();
+        let trail = algo::hierholzer(index.len(), &edges, true, circuit)?;
+        Some(trail.edges.into_iter().map(|i| ids[i]).collect())
+    }
+
+    /// simple graph with the lightest of every group of parallel edges.
+    pub fn to_simple(&self) -> super::Graph<K, T, W, S>
+    where
+        T: Clone,
+        W: Ord + Clone,
+    {
+        let mut graph = super::Graph::default();
+        for (key, node) in self.map.iter() {
+            graph.insert_node(key.clone(), node.val.clone());
+        }
+        for (from, node) in self.map.iter() {
+            for to in node.outs.keys() {
+                if let Some(edge) = self.min_edge(from, to) {
+                    graph
+                        .insert_edge(from.clone(), to.clone(), edge.weight.clone())
+                        .unwrap();
+                }
+            }
+        }
+        graph
+    }
+}
+
+impl<K, T, S> Graph<K, T, usize, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    /// Dijkstra's algorithm, only the lightest of parallel edges is ever used.
+    ///
+    /// # Returns
+    /// edges of a shortest path and its length.
+    pub fn dijkstra_shortest_path(
+        &self,
+        start_node_key: &K,
+        dest_node_key: &K,
+    ) -> Option<(Vec<EdgeId>, usize)> {
+        let paths = self.dijkstra(start_node_key, Some(dest_node_key))?;
+        let len = *paths.dist.get(dest_node_key)?;
+        let mut path = Vec::new();
+        let mut key = dest_node_key;
+        while let Some(id) = paths.prev.get(key) {
+            path.push(*id);
+            key = &self.edges[id].from;
+        }
+        path.reverse();
+        Some((path, len))
+    }
+
+    /// # Returns
+    /// shortest distance to every node reachable from `start_node_key`.
+    pub fn dijkstra_shortest_dist(&self, start_node_key: &K) -> HashMap<&K, usize> {
+        self.dijkstra(start_node_key, None)
+            .map(|paths| paths.dist)
+            .unwrap_or_default()
+    }
+
+    fn dijkstra<'a>(
+        &'a self,
+        start_node_key: &K,
+        dest_node_key: Option<&K>,
+    ) -> Option<ShortestPaths<'a, K>> {
+        let (start, _) = self.map.get_key_value(start_node_key)?;
+        let mut dist: HashMap<&K, usize> = HashMap::from([(start, 0)]);
+        let mut prev: HashMap<&K, EdgeId> = HashMap::new();
+        let mut prio = BinaryHeap::from([Reverse(DijkstraPair(start, 0))]);
+
+        while let Some(Reverse(DijkstraPair(key, key_dist))) = prio.pop() {
+            if key_dist > dist[key] {
+                continue;
+            }
+            if Some(key) == dest_node_key {
+                break;
+            }
+            for (neib, ids) in self.map[key].outs.iter() {
+                let Some(id) = ids.iter().min_by_key(|id| self.edges[id].weight) else {
+                    continue;
+                };
+                let new_dist = key_dist + self.edges[id].weight;
+                if dist.get(neib).is_none_or(|d| new_dist < *d) {
+                    dist.insert(neib, new_dist);
+                    prev.insert(neib, *id);
+                    prio.push(Reverse(DijkstraPair(neib, new_dist)));
+                }
+            }
+        }
+        Some(ShortestPaths { dist, prev })
+    }
+}
+
+impl<K, T, W, S> Default for Graph<K, T, W, S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self {
+            map: HashMap::default(),
+            edges: HashMap::default(),
+            next_id: 0,
+        }
+    }
+}
+
+impl<K, W> EdgeEntry<K, W> {
+    fn as_edge(&self, id: EdgeId) -> Edge<'_, K, W> {
+        Edge {
+            id,
+            from: &self.from,
+            to: &self.to,
+            weight: &self.weight,
+        }
+    }
+}
+
+fn remove_id<K, S>(ids: &mut HashMap<K, Vec<EdgeId>, S>, key: &K, id: EdgeId)
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    if let Some(list) = ids.get_mut(key) {
+        list.retain(|i| *i != id);
+        if list.is_empty() {
+            ids.remove(key);
+        }
+    }
+}
+
+impl<K, T, S> Node<K, T, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    fn new(key: K, val: T) -> Self {
+        Self {
+            key,
+            val,
+            outs: HashMap::default(),
+            ins: HashMap::default(),
+        }
+    }
+
+    pub const fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub const fn val(&self) -> &T {
+        &self.val
+    }
+
+    pub fn val_mut(&mut self) -> &mut T {
+        &mut self.val
+    }
+
+    /// ids of outgoing edges grouped by the node they lead to.
+    pub const fn neighbors(&self) -> &HashMap<K, Vec<EdgeId>, S> {
+        &self.outs
+    }
+
+    /// ids of incoming edges grouped by the node they come from.
+    pub const fn in_neighbors(&self) -> &HashMap<K, Vec<EdgeId>, S> {
+        &self.ins
+    }
+}
+
+impl<K, T, W, S> Display for Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone + Debug,
+    T: Debug,
+    W: Debug,
+    S: BuildHasher + Default,
+{
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        writeln!(f, "Graph {{")?;
+        writeln!(f, "    nodes: [")?;
+        for (k, v) in self.iter() {
+            writeln!(f, "        {{ key: {:?}, val: {:?} }},", k, v.val)?;
+        }
+        writeln!(f, "    ]")?;
+        writeln!(f, "\n    edges: [")?;
+        for i in self.edges().iter() {
+            writeln!(f, "        {:?},", i)?;
+        }
+        writeln!(f, "    ]")?;
+        writeln!(f, "}}")?;
+        Ok(())
+    }
+}
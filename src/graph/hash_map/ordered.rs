@@ -0,0 +1,100 @@
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
+/// map that iterates in insertion order, like `IndexMap`. a removed entry leaves a
+/// hole that's skipped, the entries are compacted once holes are more than half of them.
+#[derive(Debug, Clone)]
+pub(super) struct OrderedMap<K, V, S> {
+    entries: Vec<Option<(K, V)>>,
+    /// position of every key in `entries`.
+    index: HashMap<K, usize, S>,
+}
+
+impl<K, V, S> OrderedMap<K, V, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    pub(super) fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            index: HashMap::default(),
+        }
+    }
+
+    pub(super) fn get(&self, key: &K) -> Option<&V> {
+        let &i = self.index.get(key)?;
+        self.entries[i].as_ref().map(|(_, v)| v)
+    }
+
+    pub(super) fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let &i = self.index.get(key)?;
+        self.entries[i].as_mut().map(|(_, v)| v)
+    }
+
+    /// position of `key` in the order, positions of removed keys aren't reused.
+    pub(super) fn position(&self, key: &K) -> Option<usize> {
+        self.index.get(key).copied()
+    }
+
+    /// an existing key keeps its position.
+    ///
+    /// # Returns
+    /// old value of `key`.
+    pub(super) fn insert(&mut self, key: K, val: V) -> Option<V> {
+        match self.index.get(&key) {
+            Some(&i) => self.entries[i].replace((key, val)).map(|(_, v)| v),
+            None => {
+                self.index.insert(key.clone(), self.entries.len());
+                self.entries.push(Some((key, val)));
+                None
+            }
+        }
+    }
+
+    pub(super) fn remove(&mut self, key: &K) -> Option<V> {
+        let i = self.index.remove(key)?;
+        let (_, val) = self.entries[i].take()?;
+        if self.entries.len() > 2 * self.index.len() {
+            self.compact();
+        }
+        Some(val)
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.entries.clear();
+        self.index.clear();
+    }
+
+    pub(super) fn keys(&self) -> Keys<'_, K, V> {
+        Keys {
+            entries: self.entries.iter(),
+        }
+    }
+
+    pub(super) fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.entries.iter_mut().flatten().map(|(_, v)| v)
+    }
+
+    fn compact(&mut self) {
+        self.entries.retain(Option::is_some);
+        for (i, (key, _)) in self.entries.iter().flatten().enumerate() {
+            *self.index.get_mut(key).unwrap() = i;
+        }
+    }
+}
+
+/// keys of an `OrderedMap` in insertion order.
+pub(super) struct Keys<'a, K, V> {
+    entries: std::slice::Iter<'a, Option<(K, V)>>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.by_ref().flatten().next().map(|(k, _)| k)
+    }
+}
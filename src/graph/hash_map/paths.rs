@@ -0,0 +1,109 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::{BuildHasher, Hash},
+    ops::Add,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use rayon::prelude::*;
+
+use super::{algo, Graph};
+
+impl<K, T, W, S> Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    /// # Returns
+    /// number of edges of a shortest path from `start` to every node reachable from
+    /// it, empty if graph doesn't contain `start`.
+    pub fn bfs_levels(&self, start: &K) -> HashMap<&K, usize> {
+        let Some((start, _)) = self.map.get_key_value(start) else {
+            return HashMap::new();
+        };
+        let mut levels: HashMap<&K, usize> = HashMap::from([(start, 0)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(key) = queue.pop_front() {
+            let next = levels[key] + 1;
+            for (neib, _) in self.map[key].neibs.iter() {
+                let Some((neib, _)) = self.map.get_key_value(neib) else {
+                    continue;
+                };
+                if !levels.contains_key(neib) {
+                    levels.insert(neib, next);
+                    queue.push_back(neib);
+                }
+            }
+        }
+        levels
+    }
+
+    /// level synchronous parallel version of `bfs_levels`, every frontier is
+    /// expanded in parallel. gives the same result.
+    pub fn bfs_levels_par(&self, start: &K) -> HashMap<&K, usize>
+    where
+        K: Sync,
+        T: Sync,
+        W: Sync,
+        S: Sync,
+    {
+        let keys: Vec<&K> = self.iter().map(|(k, _)| k).collect();
+        let index: HashMap<&K, usize> = keys.iter().enumerate().map(|(i, k)| (*k, i)).collect();
+        let Some(&start) = index.get(start) else {
+            return HashMap::new();
+        };
+        let levels: Vec<AtomicUsize> = (0..keys.len())
+            .map(|_| AtomicUsize::new(usize::MAX))
+            .collect();
+        levels[start].store(0, Ordering::Relaxed);
+        let mut frontier = vec![start];
+        let mut level = 0;
+        while !frontier.is_empty() {
+            level += 1;
+            frontier = frontier
+                .par_iter()
+                .flat_map_iter(|&u| self.map[keys[u]].neibs.keys())
+                .filter_map(|neib| index.get(neib).copied())
+                .filter(|&v| {
+                    levels[v]
+                        .compare_exchange(usize::MAX, level, Ordering::Relaxed, Ordering::Relaxed)
+                        .is_ok()
+                })
+                .collect();
+        }
+        keys.into_iter()
+            .zip(levels)
+            .map(|(key, l)| (key, l.into_inner()))
+            .filter(|(_, l)| *l != usize::MAX)
+            .collect()
+    }
+
+    /// Dijkstra's algorithm from every node, weights must not be negative.
+    ///
+    /// # Returns
+    /// distance from every node to every node reachable from it.
+    pub fn all_pairs_shortest_dist(&self) -> HashMap<&K, HashMap<&K, W>>
+    where
+        W: Copy + Ord + Default + Add<Output = W>,
+    {
+        self.map
+            .keys()
+            .map(|key| (key, algo::dijkstra(self, key)))
+            .collect()
+    }
+
+    /// parallel version of `all_pairs_shortest_dist`, the sources are run in
+    /// parallel. gives the same result.
+    pub fn all_pairs_shortest_dist_par(&self) -> HashMap<&K, HashMap<&K, W>>
+    where
+        K: Sync,
+        T: Sync,
+        W: Copy + Ord + Default + Add<Output = W> + Send + Sync,
+        S: Sync,
+    {
+        self.map
+            .par_iter()
+            .map(|(key, _)| (key, algo::dijkstra(self, key)))
+            .collect()
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
+use crate::matrix::bit::BitMatrix;
+
+use super::Graph;
+
+/// answers whether a node can reach another with one bit lookup. made by
+/// `Graph::reachability`, it stores a bit for every pair of strongly connected
+/// components and doesn't see later changes of the graph.
+#[derive(Debug, Clone)]
+pub struct Reachability<'a, K> {
+    /// index of the component of every node.
+    component: HashMap<&'a K, usize>,
+    /// components in reverse topological order.
+    components: Vec<Vec<&'a K>>,
+    /// row `c` has every component reachable from component `c`, itself included.
+    closure: BitMatrix,
+}
+
+impl<'a, K> Reachability<'a, K>
+where
+    K: Hash + Eq,
+{
+    /// # Returns
+    /// true if there is a path from `from` to `to`, every node reaches itself.
+    /// false if graph doesn't contain `from` or `to`.
+    pub fn reaches(&self, from: &K, to: &K) -> bool {
+        match (self.component.get(from), self.component.get(to)) {
+            (Some(&a), Some(&b)) => self.closure.get(a, b) == Some(true),
+            _ => false,
+        }
+    }
+
+    /// # Returns
+    /// every node `from` reaches including itself, None if graph doesn't contain `from`.
+    pub fn reachable_from(&self, from: &K) -> Option<Vec<&'a K>> {
+        let &c = self.component.get(from)?;
+        Some(
+            self.closure
+                .row_ones(c)
+                .flat_map(|d| self.components[d].iter().copied())
+                .collect(),
+        )
+    }
+
+    /// `self_loop` is whether `key` has an edge to itself.
+    ///
+    /// # Returns
+    /// true if `key` is on a cycle, false if graph doesn't contain `key`.
+    fn on_cycle(&self, key: &K, self_loop: bool) -> bool {
+        self.component
+            .get(key)
+            .is_some_and(|&c| self_loop || self.components[c].len() > 1)
+    }
+}
+
+impl<K, T, W, S> Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    /// reachability index of the graph. for `c` strongly connected components it
+    /// takes `O(n + e * c / 64)` time and `c * c` bits.
+    pub fn reachability(&self) -> Reachability<'_, K> {
+        let components = self.tarjan_scc();
+        let mut component: HashMap<&K, usize> = HashMap::with_capacity(self.map.len());
+        for (i, keys) in components.iter().enumerate() {
+            for &key in keys {
+                component.insert(key, i);
+            }
+        }
+        // components a component has edges to come before it
+        let mut closure = BitMatrix::new(components.len(), components.len());
+        for (i, keys) in components.iter().enumerate() {
+            closure.set(i, i, true);
+            for &key in keys {
+                for neib in self.map[key].neibs.keys() {
+                    match component.get(neib) {
+                        Some(&j) if j != i && closure.get(i, j) == Some(false) => {
+                            closure.or_row(i, j)
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        Reachability {
+            component,
+            components,
+            closure,
+        }
+    }
+
+    /// graph with an edge from every node to every other node it reaches, a node on
+    /// a cycle gets a self loop. edges of `self` keep their weight, new ones get `weight`.
+    pub fn transitive_closure(&self, weight: W) -> Self
+    where
+        T: Clone,
+        W: Clone,
+    {
+        let reach = self.reachability();
+        let mut edges: Vec<(K, K, W)> = self
+            .edges()
+            .into_iter()
+            .map(|e| (e.from.clone(), e.to.clone(), e.weight.clone()))
+            .collect();
+        for (key, _) in self.iter() {
+            let self_loop = self.has_edge(key, key);
+            for to in reach.reachable_from(key).unwrap() {
+                if self.has_edge(key, to) || (to == key && !reach.on_cycle(key, self_loop)) {
+                    continue;
+                }
+                edges.push((key.clone(), to.clone(), weight.clone()));
+            }
+        }
+        self.rebuild(self.iter().map(|(k, n)| (k.clone(), n.val.clone())), edges)
+    }
+
+    /// graph with the fewest edges that has the same reachability, an edge is kept
+    /// if there is no other path between its ends. weights are kept.
+    ///
+    /// # Returns
+    /// None if the graph isn't a DAG.
+    pub fn transitive_reduction(&self) -> Option<Self>
+    where
+        T: Clone,
+        W: Clone,
+    {
+        let reach = self.reachability();
+        if self
+            .iter()
+            .any(|(key, _)| reach.on_cycle(key, self.has_edge(key, key)))
+        {
+            return None;
+        }
+        let edges: Vec<(K, K, W)> = self
+            .edges()
+            .into_iter()
+            .filter(|e| {
+                // another neighbor of `from` reaching `to` makes the edge redundant
+                !self.map[e.from]
+                    .neibs
+                    .keys()
+                    .any(|other| other != e.to && reach.reaches(other, e.to))
+            })
+            .map(|e| (e.from.clone(), e.to.clone(), e.weight.clone()))
+            .collect();
+        Some(self.rebuild(self.iter().map(|(k, n)| (k.clone(), n.val.clone())), edges))
+    }
+}
@@ -0,0 +1,199 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    hash::{BuildHasher, Hash},
+};
+
+use super::{Graph, OutNeighbors};
+
+impl<K, T, W, S> Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    /// depth first search over the whole graph, restarting from every unvisited node.
+    ///
+    /// # Returns
+    /// `(discovery, finish)` times of every node, both counted on the same clock.
+    pub fn dfs_times(&self) -> HashMap<&K, (usize, usize)> {
+        let mut times: HashMap<&K, (usize, usize)> = HashMap::with_capacity(self.map.len());
+        let mut clock = 0;
+        let mut stack: Vec<(&K, OutNeighbors<K, W, S>)> = Vec::new();
+
+        for (start, node) in self.iter() {
+            if times.contains_key(start) {
+                continue;
+            }
+            times.insert(start, (clock, 0));
+            clock += 1;
+            stack.push((start, self.neighbors_of(node)));
+
+            while let Some((key, neibs)) = stack.last_mut() {
+                let key = *key;
+                match neibs.next() {
+                    Some((next, _)) => {
+                        if !times.contains_key(next) {
+                            if let Some(node) = self.map.get(next) {
+                                times.insert(next, (clock, 0));
+                                clock += 1;
+                                stack.push((next, self.neighbors_of(node)));
+                            }
+                        }
+                    }
+                    None => {
+                        times.get_mut(key).unwrap().1 = clock;
+                        clock += 1;
+                        stack.pop();
+                    }
+                }
+            }
+        }
+        times
+    }
+
+    /// Tarjan's strongly connected components algorithm.
+    ///
+    /// # Returns
+    /// components in reverse topological order, a component only has edges to
+    /// components that come before it.
+    pub fn tarjan_scc(&self) -> Vec<Vec<&K>> {
+        let mut index: HashMap<&K, usize> = HashMap::with_capacity(self.map.len());
+        let mut low: HashMap<&K, usize> = HashMap::with_capacity(self.map.len());
+        let mut on_stack: HashSet<&K> = HashSet::new();
+        let mut scc_stack: Vec<&K> = Vec::new();
+        let mut call_stack: Vec<(&K, OutNeighbors<K, W, S>)> = Vec::new();
+        let mut components = Vec::new();
+
+        for (start, node) in self.iter() {
+            if index.contains_key(start) {
+                continue;
+            }
+            index.insert(start, index.len());
+            low.insert(start, index[start]);
+            scc_stack.push(start);
+            on_stack.insert(start);
+            call_stack.push((start, self.neighbors_of(node)));
+
+            while let Some((key, neibs)) = call_stack.last_mut() {
+                let key = *key;
+                if let Some((next, _)) = neibs.next() {
+                    if let Some(&next_index) = index.get(next) {
+                        if on_stack.contains(next) {
+                            let low_key = low.get_mut(key).unwrap();
+                            *low_key = (*low_key).min(next_index);
+                        }
+                    } else if let Some(node) = self.map.get(next) {
+                        index.insert(next, index.len());
+                        low.insert(next, index[next]);
+                        scc_stack.push(next);
+                        on_stack.insert(next);
+                        call_stack.push((next, self.neighbors_of(node)));
+                    }
+                    continue;
+                }
+
+                call_stack.pop();
+                let low_key = low[key];
+                if let Some((parent, _)) = call_stack.last() {
+                    let low_parent = low.get_mut(parent).unwrap();
+                    *low_parent = (*low_parent).min(low_key);
+                }
+                if low_key == index[key] {
+                    let mut component = Vec::new();
+                    while let Some(member) = scc_stack.pop() {
+                        on_stack.remove(member);
+                        component.push(member);
+                        if member == key {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+
+    /// Kosaraju's strongly connected components algorithm.
+    ///
+    /// # Returns
+    /// components in topological order, a component only has edges to
+    /// components that come after it.
+    pub fn kosaraju_scc(&self) -> Vec<Vec<&K>> {
+        let mut order: Vec<(&K, (usize, usize))> = self.dfs_times().into_iter().collect();
+        order.sort_unstable_by_key(|(_, (_, finish))| Reverse(*finish));
+
+        let mut transposed: HashMap<&K, Vec<&K>> = HashMap::with_capacity(self.map.len());
+        for (key, node) in self.iter() {
+            for (neib, _) in self.neighbors_of(node) {
+                transposed.entry(neib).or_default().push(key);
+            }
+        }
+
+        let mut visited: HashSet<&K> = HashSet::with_capacity(self.map.len());
+        let mut components = Vec::new();
+        for (start, _) in order {
+            if !visited.insert(start) {
+                continue;
+            }
+            let mut component = Vec::new();
+            let mut stack = vec![start];
+            while let Some(key) = stack.pop() {
+                component.push(key);
+                for &prev in transposed.get(key).into_iter().flatten() {
+                    if visited.insert(prev) {
+                        stack.push(prev);
+                    }
+                }
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    /// collapses every strongly connected component into a single node.
+    ///
+    /// # Returns
+    /// a DAG keyed by component index in topological order, each node holding
+    /// the keys of its component. parallel edges between two components keep
+    /// the lightest weight.
+    pub fn condensation(&self) -> Graph<usize, Vec<K>, W>
+    where
+        W: Clone + PartialOrd,
+    {
+        let components = self.tarjan_scc();
+        let mut component_of: HashMap<&K, usize> = HashMap::with_capacity(self.map.len());
+        let mut dag = Graph::new();
+        for (i, component) in components.into_iter().rev().enumerate() {
+            let mut keys = Vec::with_capacity(component.len());
+            for key in component {
+                component_of.insert(key, i);
+                keys.push(key.clone());
+            }
+            dag.insert_node(i, keys);
+        }
+
+        for (key, node) in self.iter() {
+            let from = component_of[key];
+            for (neib, weight) in self.neighbors_of(node) {
+                let Some(&to) = component_of.get(neib) else {
+                    continue;
+                };
+                if from == to {
+                    continue;
+                }
+                match dag.get_weight_mut(from, to) {
+                    Ok(old) => {
+                        if weight < old {
+                            *old = weight.clone();
+                        }
+                    }
+                    Err(_) => {
+                        dag.insert_edge(from, to, weight.clone()).unwrap();
+                    }
+                }
+            }
+        }
+        dag
+    }
+}
@@ -0,0 +1,234 @@
+use std::hash::{BuildHasher, Hash};
+
+use super::{EdgeErr, Graph};
+
+impl<K, T, W, S> Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    /// graph with the given nodes and every edge whose ends are both among them.
+    /// it keeps a reverse index and insertion order if this graph does.
+    pub(super) fn rebuild<T2, W2>(
+        &self,
+        nodes: impl IntoIterator<Item = (K, T2)>,
+        edges: impl IntoIterator<Item = (K, K, W2)>,
+    ) -> Graph<K, T2, W2, S> {
+        let mut graph = Graph::default();
+        if self.has_reverse_index() {
+            graph.enable_reverse_index();
+        }
+        if self.has_insertion_order() {
+            graph.enable_insertion_order();
+        }
+        for (key, val) in nodes {
+            graph.insert_node(key, val);
+        }
+        for (from, to, weight) in edges {
+            // an edge to a dropped node is skipped, `from` is checked by `insert_edge`
+            let _ = graph.insert_edge(from, to, weight);
+        }
+        graph
+    }
+
+    /// copy of the graph with every edge pointing the other way.
+    pub fn reversed(&self) -> Self
+    where
+        T: Clone,
+        W: Clone,
+    {
+        self.rebuild(
+            self.iter().map(|(k, n)| (k.clone(), n.val.clone())),
+            self.edges()
+                .into_iter()
+                .map(|e| (e.to.clone(), e.from.clone(), e.weight.clone())),
+        )
+    }
+
+    /// copy of the nodes of `keys` that are in the graph and the edges between them.
+    pub fn induced_subgraph<'a, I>(&self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = &'a K>,
+        K: 'a,
+        T: Clone,
+        W: Clone,
+    {
+        let nodes: Vec<(K, T)> = keys
+            .into_iter()
+            .filter_map(|k| self.map.get_key_value(k))
+            .map(|(k, n)| (k.clone(), n.val.clone()))
+            .collect();
+        let edges: Vec<(K, K, W)> = nodes
+            .iter()
+            .flat_map(|(k, _)| {
+                self.out_neighbors(k)
+                    .unwrap()
+                    .map(move |(to, w)| (k.clone(), to.clone(), w.clone()))
+            })
+            .collect();
+        self.rebuild(nodes, edges)
+    }
+
+    /// new graph with the nodes `node_fn` returns a value for and the edges `edge_fn`
+    /// returns a weight for. edges of removed nodes are removed too.
+    pub fn filter_map<T2, W2, N, E>(&self, mut node_fn: N, mut edge_fn: E) -> Graph<K, T2, W2, S>
+    where
+        N: FnMut(&K, &T) -> Option<T2>,
+        E: FnMut(&K, &K, &W) -> Option<W2>,
+    {
+        let nodes: Vec<(K, T2)> = self
+            .iter()
+            .filter_map(|(k, n)| Some((k.clone(), node_fn(k, &n.val)?)))
+            .collect();
+        let edges: Vec<(K, K, W2)> = self
+            .edges()
+            .into_iter()
+            .filter_map(|e| {
+                Some((
+                    e.from.clone(),
+                    e.to.clone(),
+                    edge_fn(e.from, e.to, e.weight)?,
+                ))
+            })
+            .collect();
+        self.rebuild(nodes, edges)
+    }
+
+    /// copy of the graph with every weight replaced by `f(from, to, weight)`.
+    pub fn map_weights<W2, F>(&self, mut f: F) -> Graph<K, T, W2, S>
+    where
+        T: Clone,
+        F: FnMut(&K, &K, &W) -> W2,
+    {
+        self.filter_map(
+            |_, val| Some(val.clone()),
+            |from, to, w| Some(f(from, to, w)),
+        )
+    }
+
+    /// merges `to` into `from`. every edge of `to` is moved to `from` unless `from`
+    /// already has an edge with the same neighbor in the same direction, edges between
+    /// the two are removed and a self loop of `to` becomes one of `from`.
+    ///
+    /// # Returns
+    /// value of the removed node `to`, None if from == to.
+    ///
+    /// # Error
+    /// if graph doesn't contain `to` returns `Err(EdgeErr::ToNone)`.
+    /// if graph doesn't contain `from` returns `Err(EdgeErr::FromNone)`.
+    pub fn contract_edge(&mut self, from: K, to: K) -> Result<Option<T>, EdgeErr> {
+        if !self.map.contains_key(&to) {
+            return Err(EdgeErr::ToNone);
+        }
+        if !self.map.contains_key(&from) {
+            return Err(EdgeErr::FromNone);
+        }
+        if from == to {
+            return Ok(None);
+        }
+        let incoming: Vec<K> = self
+            .in_neighbors(&to)
+            .unwrap()
+            .into_iter()
+            .filter(|k| **k != from && **k != to)
+            .cloned()
+            .collect();
+        for key in incoming {
+            let weight = self.remove_edge(key.clone(), to.clone()).unwrap().unwrap();
+            if !self.map[&key].neibs.contains_key(&from) {
+                self.insert_edge(key, from.clone(), weight).unwrap();
+            }
+        }
+        let node = self.remove(to.clone()).unwrap();
+        for (key, weight) in node.neibs {
+            if key == from {
+                continue;
+            }
+            let key = if key == to { from.clone() } else { key };
+            if self.map.contains_key(&key) && !self.map[&from].neibs.contains_key(&key) {
+                self.insert_edge(from.clone(), key, weight).unwrap();
+            }
+        }
+        Ok(Some(node.val))
+    }
+
+    /// nodes and edges of both graphs, values and weights of `self` are kept for
+    /// the ones in both.
+    pub fn union(&self, other: &Self) -> Self
+    where
+        T: Clone,
+        W: Clone,
+    {
+        let mut nodes: Vec<(K, T)> = self
+            .iter()
+            .map(|(k, n)| (k.clone(), n.val.clone()))
+            .collect();
+        nodes.extend(
+            other
+                .iter()
+                .filter(|(k, _)| !self.contains(k))
+                .map(|(k, n)| (k.clone(), n.val.clone())),
+        );
+        let mut edges: Vec<(K, K, W)> = self
+            .edges()
+            .into_iter()
+            .map(|e| (e.from.clone(), e.to.clone(), e.weight.clone()))
+            .collect();
+        edges.extend(
+            other
+                .edges()
+                .into_iter()
+                .filter(|e| !self.has_edge(e.from, e.to))
+                .map(|e| (e.from.clone(), e.to.clone(), e.weight.clone())),
+        );
+        self.rebuild(nodes, edges)
+    }
+
+    /// nodes and edges in both graphs with the values and weights of `self`.
+    pub fn intersection<T2, W2>(&self, other: &Graph<K, T2, W2, S>) -> Self
+    where
+        T: Clone,
+        W: Clone,
+    {
+        self.filter_map(
+            |k, val| other.contains(k).then(|| val.clone()),
+            |from, to, w| other.has_edge(from, to).then(|| w.clone()),
+        )
+    }
+
+    /// every node of `self` and its edges that aren't in `other`.
+    pub fn difference<T2, W2>(&self, other: &Graph<K, T2, W2, S>) -> Self
+    where
+        T: Clone,
+        W: Clone,
+    {
+        self.filter_map(
+            |_, val| Some(val.clone()),
+            |from, to, w| (!other.has_edge(from, to)).then(|| w.clone()),
+        )
+    }
+
+    /// graph with the same nodes and an edge of `weight` between every ordered pair
+    /// of different nodes that don't have one in `self`. self loops are dropped.
+    pub fn complement(&self, weight: W) -> Self
+    where
+        T: Clone,
+        W: Clone,
+    {
+        let edges: Vec<(K, K, W)> = self
+            .iter()
+            .flat_map(|(from, node)| {
+                self.iter()
+                    .map(|(to, _)| to)
+                    .filter(move |to| *to != from && !node.neibs.contains_key(*to))
+                    .map(move |to| (from.clone(), to.clone()))
+            })
+            .map(|(from, to)| (from, to, weight.clone()))
+            .collect();
+        self.rebuild(self.iter().map(|(k, n)| (k.clone(), n.val.clone())), edges)
+    }
+
+    pub(super) fn has_edge(&self, from: &K, to: &K) -> bool {
+        self.map.get(from).is_some_and(|n| n.neibs.contains_key(to))
+    }
+}
@@ -0,0 +1,347 @@
+use std::{
+    collections::{hash_map::RandomState, HashSet},
+    fmt::{Debug, Display, Formatter},
+    hash::{BuildHasher, Hash},
+    ops::Add,
+};
+
+use super::{BfsIter, DfsIter, Edge, EdgeErr, Iter, Node};
+use crate::graph::algo;
+
+/// undirected graph, every edge is stored in the neighbors of both of its
+/// nodes so lookups work from either side and are always symmetric.
+#[derive(Debug)]
+pub struct Graph<K, T, W, S = RandomState> {
+    graph: super::Graph<K, T, W, S>,
+}
+
+impl<K, T, W> Graph<K, T, W>
+where
+    K: Hash + Eq + Clone,
+{
+    /// graph with the default hasher, `Graph::default()` makes one with any other.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// graph that iterates its nodes and edges in the order they were inserted.
+    pub fn with_insertion_order() -> Self {
+        let mut graph = Self::new();
+        graph.graph.enable_insertion_order();
+        graph
+    }
+}
+
+impl<K, T, W, S> Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    pub fn has_insertion_order(&self) -> bool {
+        self.graph.has_insertion_order()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+
+    pub fn get(&self, node_key: &K) -> Option<&Node<K, T, W, S>> {
+        self.graph.get(node_key)
+    }
+
+    pub fn get_val_mut(&mut self, node_key: &K) -> Option<&mut T> {
+        self.graph.get_mut(node_key).map(|n| n.val_mut())
+    }
+
+    pub fn contains(&self, node_key: &K) -> bool {
+        self.graph.contains(node_key)
+    }
+
+    /// # Returns
+    /// returns old value if graph has the node_key, its edges are kept.
+    /// returns None if graph doesn't have the node_key.
+    pub fn insert_node(&mut self, key: K, val: T) -> Option<T> {
+        match self.graph.get_mut(&key) {
+            Some(node) => Some(std::mem::replace(node.val_mut(), val)),
+            None => {
+                self.graph.insert_node(key, val);
+                None
+            }
+        }
+    }
+
+    /// removes the node and every edge touching it.
+    pub fn remove(&mut self, node_key: K) -> Option<Node<K, T, W, S>> {
+        let neibs: Vec<K> = self.graph.get(&node_key)?.neibs.keys().cloned().collect();
+        // every incoming edge is mirrored by an outgoing one
+        for neib in neibs {
+            self.graph.remove_edge(neib, node_key.clone()).unwrap();
+        }
+        if let Some(order) = self.graph.order.as_mut() {
+            order.remove(&node_key);
+        }
+        self.graph.map.remove(&node_key)
+    }
+
+    pub fn nodes(&self) -> Vec<&Node<K, T, W, S>> {
+        self.graph.nodes()
+    }
+
+    pub fn nodes_len(&self) -> usize {
+        self.graph.nodes_len()
+    }
+
+    /// every edge is reported once, in the direction it's first found.
+    pub fn edges(&self) -> Vec<Edge<'_, K, W>> {
+        let mut ret: Vec<Edge<K, W>> = Vec::new();
+        let mut done: HashSet<&K> = HashSet::with_capacity(self.graph.map.len());
+        for (key, node) in self.graph.iter() {
+            for (neib, weight) in self.graph.neighbors_of(node) {
+                if !done.contains(neib) {
+                    ret.push(Edge::new(key, neib, weight));
+                }
+            }
+            done.insert(key);
+        }
+        ret
+    }
+
+    pub fn edges_len(&self) -> usize {
+        let mut ends = 0;
+        let mut loops = 0;
+        for (key, node) in self.graph.iter() {
+            ends += node.neibs.len();
+            if node.neibs.contains_key(key) {
+                loops += 1;
+            }
+        }
+        (ends + loops) / 2
+    }
+
+    /// number of edges touching the node, a self loop counts twice.
+    pub fn degree(&self, node_key: &K) -> Option<usize> {
+        let node = self.graph.get(node_key)?;
+        let loops = node.neibs.contains_key(node_key) as usize;
+        Some(node.neibs.len() + loops)
+    }
+
+    /// # Error
+    /// if graph doesn't contain `y` returns `Err(EdgeErr::ToNone)`.
+    /// if graph doesn't contain `x` returns `Err(EdgeErr::FromNone)`.
+    pub fn insert_edge(&mut self, x: K, y: K, weight: W) -> Result<(), EdgeErr>
+    where
+        W: Clone,
+    {
+        self.check_ends(&x, &y)?;
+        if x != y {
+            self.graph
+                .insert_edge(y.clone(), x.clone(), weight.clone())?;
+        }
+        self.graph.insert_edge(x, y, weight)
+    }
+
+    /// # Returns
+    /// if there's an edge between `x` and `y` returns its weight.
+    ///
+    /// # Error
+    /// if graph doesn't contain `y` returns `Err(EdgeErr::ToNone)`.
+    /// if graph doesn't contain `x` returns `Err(EdgeErr::FromNone)`.
+    pub fn remove_edge(&mut self, x: K, y: K) -> Result<Option<W>, EdgeErr> {
+        self.check_ends(&x, &y)?;
+        if x != y {
+            self.graph.remove_edge(y.clone(), x.clone())?;
+        }
+        self.graph.remove_edge(x, y)
+    }
+
+    /// # Error
+    /// if graph doesn't contain `x`'s Node, returns `Err(EdgeErr::FromNone)`.
+    /// if `x`'s Node doesn't contain `y` neighbor, returns `Err(EdgeErr::ToNone)`.
+    pub fn get_weight(&self, x: K, y: K) -> Result<&W, EdgeErr> {
+        self.graph.get_weight(x, y)
+    }
+
+    /// updates the weight of both directions of the edge.
+    ///
+    /// # Returns
+    /// old weight.
+    ///
+    /// # Error
+    /// if graph doesn't contain `x`'s Node, returns `Err(EdgeErr::FromNone)`.
+    /// if `x`'s Node doesn't contain `y` neighbor, returns `Err(EdgeErr::ToNone)`.
+    pub fn set_weight(&mut self, x: K, y: K, weight: W) -> Result<W, EdgeErr>
+    where
+        W: Clone,
+    {
+        let old = self.graph.get_weight_mut(x.clone(), y.clone())?;
+        let old = std::mem::replace(old, weight.clone());
+        if x != y {
+            *self.graph.get_weight_mut(y, x)? = weight;
+        }
+        Ok(old)
+    }
+
+    fn check_ends(&self, x: &K, y: &K) -> Result<(), EdgeErr> {
+        if !self.graph.contains(y) {
+            Err(EdgeErr::ToNone)
+        } else if !self.graph.contains(x) {
+            Err(EdgeErr::FromNone)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, K, T, W, S> {
+        self.graph.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.graph.clear();
+    }
+
+    pub fn dfs_iter<'a>(&'a self, start_node_key: &'a K) -> DfsIter<'a, K, T, W, S> {
+        self.graph.dfs_iter(start_node_key)
+    }
+
+    pub fn bfs_iter<'a>(&'a self, start_node_key: &'a K) -> BfsIter<'a, K, T, W, S> {
+        self.graph.bfs_iter(start_node_key)
+    }
+
+    /// # Returns
+    /// nodes of a path using every edge exactly once, a single node if the graph has no edges.
+    /// None if the graph is empty or has no Eulerian path.
+    pub fn find_eulerian_path(&self) -> Option<Vec<&K>> {
+        algo::eulerian_path_undirected(self)
+    }
+
+    pub fn has_eulerian_path(&self) -> bool {
+        self.find_eulerian_path().is_some()
+    }
+
+    /// # Returns
+    /// nodes of a closed path using every edge exactly once, the first node is repeated at the end.
+    /// None if the graph is empty or has no Eulerian circuit.
+    pub fn find_eulerian_circuit(&self) -> Option<Vec<&K>> {
+        algo::eulerian_circuit_undirected(self)
+    }
+
+    pub fn has_eulerian_circuit(&self) -> bool {
+        self.find_eulerian_circuit().is_some()
+    }
+
+    /// # Panics
+    /// if the graph has more than `algo::HAMILTONIAN_MAX_NODES` nodes
+    pub fn find_hamiltonian_path(&self) -> Option<Vec<&K>> {
+        algo::hamiltonian_path(self)
+    }
+
+    /// # Returns
+    /// nodes of a cycle visiting every node once, the last one has an edge with the first.
+    ///
+    /// # Panics
+    /// if the graph has more than `algo::HAMILTONIAN_MAX_NODES` nodes
+    pub fn find_hamiltonian_cycle(&self) -> Option<Vec<&K>> {
+        algo::hamiltonian_cycle_undirected(self)
+    }
+
+    /// the underlying directed graph with both directions of every edge,
+    /// for running the directed algorithms.
+    pub const fn as_directed(&self) -> &super::Graph<K, T, W, S> {
+        &self.graph
+    }
+
+    pub fn into_directed(self) -> super::Graph<K, T, W, S> {
+        self.graph
+    }
+}
+
+impl<K, T, W, S> Default for Graph<K, T, W, S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self {
+            graph: super::Graph::default(),
+        }
+    }
+}
+
+impl<K, T, W, S> Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+    W: Ord + Clone + Default + Add<Output = W>,
+{
+    /// Kruskal's algorithm.
+    ///
+    /// # Returns
+    /// edges of a minimum spanning tree for every connected component and their total weight.
+    pub fn minimum_spanning_forest(&self) -> (Vec<Edge<'_, K, W>>, W) {
+        self.graph.minimum_spanning_forest()
+    }
+
+    /// Prim's algorithm.
+    ///
+    /// # Returns
+    /// edges of a minimum spanning tree for every connected component and their total weight.
+    pub fn minimum_spanning_forest_prim(&self) -> (Vec<Edge<'_, K, W>>, W) {
+        self.graph.minimum_spanning_forest_prim()
+    }
+}
+
+impl<K, T, W, S> From<super::Graph<K, T, W, S>> for Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone + Ord,
+    W: Clone,
+    S: BuildHasher + Default,
+{
+    /// mirrors every edge, if both directions exist the weight of the one from the
+    /// smaller key is kept. the reverse index is dropped since every node's neighbors
+    /// are also its incoming edges.
+    fn from(value: super::Graph<K, T, W, S>) -> Self {
+        let mut graph = value;
+        graph.disable_reverse_index();
+        let edges: Vec<(K, K, W)> = graph
+            .edges()
+            .into_iter()
+            .map(|e| (e.from.clone(), e.to.clone(), e.weight.clone()))
+            .collect();
+        for (from, to, weight) in edges {
+            // the other direction is mirrored instead
+            if !graph.map.contains_key(&to) || (to < from && graph.has_edge(&to, &from)) {
+                continue;
+            }
+            graph
+                .insert_edge(to.clone(), from.clone(), weight.clone())
+                .unwrap();
+            graph.insert_edge(from, to, weight).unwrap();
+        }
+        Self { graph }
+    }
+}
+
+impl<'a, K, T, W, S> IntoIterator for &'a Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    type Item = <&'a super::Graph<K, T, W, S> as IntoIterator>::Item;
+    type IntoIter = <&'a super::Graph<K, T, W, S> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self.graph).into_iter()
+    }
+}
+
+impl<K, T, W, S> Display for Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone + Debug,
+    T: Debug,
+    W: Debug + Clone,
+    S: BuildHasher + Default,
+{
+    /// like the directed graph but with every edge once.
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        super::fmt_graph(f, self.iter(), &self.edges())
+    }
+}
@@ -0,0 +1,382 @@
+use std::{
+    fmt::{Display, Write},
+    hash::{BuildHasher, Hash},
+    str::FromStr,
+};
+
+use super::{ensure_node, insert_edge, Cursor, ParseErr, ParseErrKind, Pos};
+use crate::graph::hash_map::{undirected, Edge, Graph};
+
+/// Graphviz `digraph`, node values and weights are written as labels.
+pub fn write_dot<K, T, W, S>(graph: &Graph<K, T, W, S>, out: &mut impl Write) -> std::fmt::Result
+where
+    K: Hash + Eq + Clone + Display,
+    T: Display,
+    W: Display,
+    S: BuildHasher + Default,
+{
+    let nodes = graph.iter().map(|(k, node)| (k, node.val()));
+    write("digraph", "->", nodes, graph.edges(), out)
+}
+
+/// Graphviz `graph`, every edge is written once.
+pub fn write_dot_undirected<K, T, W, S>(
+    graph: &undirected::Graph<K, T, W, S>,
+    out: &mut impl Write,
+) -> std::fmt::Result
+where
+    K: Hash + Eq + Clone + Display,
+    T: Display,
+    W: Display,
+    S: BuildHasher + Default,
+{
+    let nodes = graph.iter().map(|(k, node)| (k, node.val()));
+    write("graph", "--", nodes, graph.edges(), out)
+}
+
+fn write<'a, K, T, W>(
+    keyword: &str,
+    op: &str,
+    nodes: impl Iterator<Item = (&'a K, &'a T)>,
+    edges: Vec<Edge<'a, K, W>>,
+    out: &mut impl Write,
+) -> std::fmt::Result
+where
+    K: Display + 'a,
+    T: Display + 'a,
+    W: Display,
+{
+    writeln!(out, "{} {{", keyword)?;
+    for (key, val) in nodes {
+        writeln!(out, "    {} [label={}];", quote(key), quote(val))?;
+    }
+    for e in edges {
+        writeln!(
+            out,
+            "    {} {} {} [label={}];",
+            quote(e.from),
+            op,
+            quote(e.to),
+            quote(e.weight)
+        )?;
+    }
+    writeln!(out, "}}")
+}
+
+fn quote(val: impl Display) -> String {
+    let mut ret = String::from('"');
+    for c in val.to_string().chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\n' => ret.push_str("\\n"),
+            c => ret.push(c),
+        }
+    }
+    ret.push('"');
+    ret
+}
+
+/// reads a `graph` or `digraph`, edges of a `graph` are inserted in both directions
+/// so the result can be turned into an `undirected::Graph`. the `label` attribute
+/// is parsed as the node value or weight, other attributes and attribute statements
+/// are skipped. subgraphs aren't supported.
+pub fn read_dot<K, T, W, S>(input: &str) -> Result<Graph<K, T, W, S>, ParseErr>
+where
+    K: Hash + Eq + Clone + FromStr,
+    T: Default + FromStr,
+    W: Default + Clone + FromStr,
+    S: BuildHasher + Default,
+{
+    let mut parser = Parser {
+        cursor: Cursor::new(input),
+        peeked: None,
+    };
+    let mut graph = Graph::default();
+
+    let (pos, mut token) = parser.next("graph")?;
+    if token.is_keyword("strict") {
+        token = parser.next("graph")?.1;
+    }
+    let directed = match token {
+        t if t.is_keyword("digraph") => true,
+        t if t.is_keyword("graph") => false,
+        t => return Err(t.unexpected(pos, "graph")),
+    };
+    let op = if directed { "->" } else { "--" };
+    if let (_, Token::Id { .. }) = parser.peek("{")? {
+        parser.next("{")?;
+    }
+    parser.expect('{')?;
+
+    loop {
+        let (pos, token) = parser.next("statement")?;
+        let text = match token {
+            Token::Punct('}') => break,
+            Token::Punct(';') => continue,
+            t if t.is_keyword("graph") || t.is_keyword("node") || t.is_keyword("edge") => {
+                parser.attrs()?;
+                continue;
+            }
+            t if t.is_keyword("subgraph") => return Err(t.unexpected(pos, "statement")),
+            Token::Id { text, .. } => text,
+            t => return Err(t.unexpected(pos, "statement")),
+        };
+        parser.port()?;
+
+        if let (_, Token::Punct('=')) = parser.peek("statement")? {
+            parser.next("=")?;
+            parser.id()?;
+            continue;
+        }
+
+        let mut ends = vec![(pos, text)];
+        while let (pos, Token::Op(o)) = parser.peek("statement")? {
+            if o != op {
+                return Err(Token::Op(o).unexpected(pos, op));
+            }
+            parser.next(op)?;
+            ends.push(parser.id()?);
+            parser.port()?;
+        }
+        let label = parser
+            .attrs()?
+            .into_iter()
+            .rfind(|(name, _, _)| name == "label");
+
+        if ends.len() == 1 {
+            let (pos, text) = ends.pop().unwrap();
+            let key: K = pos.key(&text)?;
+            ensure_node(&mut graph, &key);
+            if let Some((_, pos, val)) = label {
+                *graph.get_mut(&key).unwrap().val_mut() = pos.val(&val)?;
+            }
+            continue;
+        }
+        let weight: W = match label {
+            Some((_, pos, weight)) => pos.weight(&weight)?,
+            None => W::default(),
+        };
+        let mut keys = Vec::with_capacity(ends.len());
+        for (pos, text) in ends {
+            keys.push(pos.key::<K>(&text)?);
+        }
+        for pair in keys.windows(2) {
+            let (from, to) = (pair[0].clone(), pair[1].clone());
+            if !directed && from != to {
+                insert_edge(&mut graph, to.clone(), from.clone(), weight.clone());
+            }
+            insert_edge(&mut graph, from, to, weight.clone());
+        }
+    }
+
+    parser.cursor.skip_whitespace();
+    match parser.cursor.peek() {
+        None => Ok(graph),
+        Some(_) => Err(parser.cursor.unexpected("end of input")),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// `quoted` ids are never keywords.
+    Id {
+        text: String,
+        quoted: bool,
+    },
+    /// `->` or `--`.
+    Op(&'static str),
+    Punct(char),
+}
+
+impl Token {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        match self {
+            Token::Id { text, quoted } => !quoted && text.eq_ignore_ascii_case(keyword),
+            _ => false,
+        }
+    }
+
+    fn unexpected(self, pos: Pos, expected: &'static str) -> ParseErr {
+        let found = match self {
+            Token::Id { text, .. } => text,
+            Token::Op(op) => op.to_string(),
+            Token::Punct(c) => c.to_string(),
+        };
+        pos.err(ParseErrKind::Unexpected { expected, found })
+    }
+}
+
+struct Parser<'a> {
+    cursor: Cursor<'a>,
+    peeked: Option<(Pos, Token)>,
+}
+
+impl Parser<'_> {
+    /// # Error
+    /// `UnexpectedEof` mentioning `expected` at the end of the input.
+    fn next(&mut self, expected: &'static str) -> Result<(Pos, Token), ParseErr> {
+        if let Some(peeked) = self.peeked.take() {
+            return Ok(peeked);
+        }
+        self.skip_comments()?;
+        let pos = self.cursor.pos();
+        let Some(c) = self.cursor.peek() else {
+            return Err(self.cursor.unexpected(expected));
+        };
+        let token = match c {
+            '"' => self.quoted()?,
+            '<' => self.html()?,
+            '-' if self.cursor.eat("->") => Token::Op("->"),
+            '-' if self.cursor.eat("--") => Token::Op("--"),
+            c if c.is_alphanumeric() || c == '_' || c == '-' || c == '.' => {
+                let mut text = String::new();
+                text.push(self.cursor.bump().unwrap());
+                while let Some(c) = self.cursor.peek() {
+                    if !(c.is_alphanumeric() || c == '_' || c == '.') {
+                        break;
+                    }
+                    text.push(self.cursor.bump().unwrap());
+                }
+                Token::Id {
+                    text,
+                    quoted: false,
+                }
+            }
+            '{' | '}' | '[' | ']' | ';' | ',' | '=' | ':' => {
+                Token::Punct(self.cursor.bump().unwrap())
+            }
+            _ => return Err(self.cursor.unexpected(expected)),
+        };
+        Ok((pos, token))
+    }
+
+    fn peek(&mut self, expected: &'static str) -> Result<(Pos, Token), ParseErr> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.next(expected)?);
+        }
+        Ok(self.peeked.clone().unwrap())
+    }
+
+    fn expect(&mut self, c: char) -> Result<Pos, ParseErr> {
+        let expected = match c {
+            '{' => "{",
+            ']' => "]",
+            _ => "=",
+        };
+        match self.next(expected)? {
+            (pos, Token::Punct(p)) if p == c => Ok(pos),
+            (pos, t) => Err(t.unexpected(pos, expected)),
+        }
+    }
+
+    fn id(&mut self) -> Result<(Pos, String), ParseErr> {
+        match self.next("id")? {
+            (pos, Token::Id { text, .. }) => Ok((pos, text)),
+            (pos, t) => Err(t.unexpected(pos, "id")),
+        }
+    }
+
+    /// skips `:port` and `:port:compass` after a node id.
+    fn port(&mut self) -> Result<(), ParseErr> {
+        while let (_, Token::Punct(':')) = self.peek("statement")? {
+            self.next(":")?;
+            self.id()?;
+        }
+        Ok(())
+    }
+
+    /// any number of `[name = value, ...]` lists.
+    ///
+    /// # Returns
+    /// name, position and text of every value.
+    fn attrs(&mut self) -> Result<Vec<(String, Pos, String)>, ParseErr> {
+        let mut ret = Vec::new();
+        while let (_, Token::Punct('[')) = self.peek("statement")? {
+            self.next("[")?;
+            loop {
+                match self.next("]")? {
+                    (_, Token::Punct(']')) => break,
+                    (_, Token::Punct(',' | ';')) => continue,
+                    (_, Token::Id { text: name, .. }) => {
+                        self.expect('=')?;
+                        let (pos, val) = self.id()?;
+                        ret.push((name, pos, val));
+                    }
+                    (pos, t) => return Err(t.unexpected(pos, "]")),
+                }
+            }
+        }
+        Ok(ret)
+    }
+
+    fn skip_comments(&mut self) -> Result<(), ParseErr> {
+        loop {
+            self.cursor.skip_whitespace();
+            if self.cursor.eat("//") || (self.cursor.col == 1 && self.cursor.eat("#")) {
+                while self.cursor.peek().is_some_and(|c| c != '\n') {
+                    self.cursor.bump();
+                }
+            } else if self.cursor.eat("/*") {
+                while !self.cursor.eat("*/") {
+                    if self.cursor.bump().is_none() {
+                        return Err(self.cursor.unexpected("*/"));
+                    }
+                }
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    /// string in double quotes, `\"` `\\` and `\n` are unescaped and other
+    /// backslashes kept as they are.
+    fn quoted(&mut self) -> Result<Token, ParseErr> {
+        self.cursor.bump();
+        let mut text = String::new();
+        loop {
+            match self.cursor.bump() {
+                Some('"') => break,
+                Some('\\') => match self.cursor.peek() {
+                    Some(c @ ('"' | '\\')) => {
+                        self.cursor.bump();
+                        text.push(c);
+                    }
+                    Some('n') => {
+                        self.cursor.bump();
+                        text.push('\n');
+                    }
+                    Some('\n') => {
+                        self.cursor.bump();
+                    }
+                    _ => text.push('\\'),
+                },
+                Some(c) => text.push(c),
+                None => return Err(self.cursor.unexpected("\"")),
+            }
+        }
+        Ok(Token::Id { text, quoted: true })
+    }
+
+    /// HTML string in angle brackets, the outer brackets are dropped.
+    fn html(&mut self) -> Result<Token, ParseErr> {
+        self.cursor.bump();
+        let mut text = String::new();
+        let mut depth = 1;
+        loop {
+            let Some(c) = self.cursor.bump() else {
+                return Err(self.cursor.unexpected(">"));
+            };
+            match c {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                break;
+            }
+            text.push(c);
+        }
+        Ok(Token::Id { text, quoted: true })
+    }
+}
@@ -0,0 +1,439 @@
+use std::{
+    collections::HashSet,
+    fmt::{Display, Write},
+    hash::{BuildHasher, Hash},
+    str::FromStr,
+};
+
+use super::{ensure_node, insert_edge, Cursor, ParseErr, ParseErrKind, Pos};
+use crate::graph::hash_map::{undirected, Edge, Graph};
+
+/// GraphML document with a `val` node attribute and a `weight` edge attribute.
+pub fn write_graphml<K, T, W, S>(
+    graph: &Graph<K, T, W, S>,
+    out: &mut impl Write,
+) -> std::fmt::Result
+where
+    K: Hash + Eq + Clone + Display,
+    T: Display,
+    W: Display,
+    S: BuildHasher + Default,
+{
+    let nodes = graph.iter().map(|(k, node)| (k, node.val()));
+    write("directed", nodes, graph.edges(), out)
+}
+
+/// like `write_graphml` with `edgedefault="undirected"`, every edge is written once.
+pub fn write_graphml_undirected<K, T, W, S>(
+    graph: &undirected::Graph<K, T, W, S>,
+    out: &mut impl Write,
+) -> std::fmt::Result
+where
+    K: Hash + Eq + Clone + Display,
+    T: Display,
+    W: Display,
+    S: BuildHasher + Default,
+{
+    let nodes = graph.iter().map(|(k, node)| (k, node.val()));
+    write("undirected", nodes, graph.edges(), out)
+}
+
+fn write<'a, K, T, W>(
+    edge_default: &str,
+    nodes: impl Iterator<Item = (&'a K, &'a T)>,
+    edges: Vec<Edge<'a, K, W>>,
+    out: &mut impl Write,
+) -> std::fmt::Result
+where
+    K: Display + 'a,
+    T: Display + 'a,
+    W: Display,
+{
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+    )?;
+    writeln!(
+        out,
+        r#"  <key id="val" for="node" attr.name="val" attr.type="string"/>"#
+    )?;
+    writeln!(
+        out,
+        r#"  <key id="weight" for="edge" attr.name="weight" attr.type="string"/>"#
+    )?;
+    writeln!(out, r#"  <graph id="G" edgedefault="{}">"#, edge_default)?;
+    for (key, val) in nodes {
+        writeln!(
+            out,
+            r#"    <node id="{}"><data key="val">{}</data></node>"#,
+            escape(key),
+            escape(val)
+        )?;
+    }
+    for e in edges {
+        writeln!(
+            out,
+            r#"    <edge source="{}" target="{}"><data key="weight">{}</data></edge>"#,
+            escape(e.from),
+            escape(e.to),
+            escape(e.weight)
+        )?;
+    }
+    writeln!(out, "  </graph>")?;
+    writeln!(out, "</graphml>")
+}
+
+fn escape(val: impl Display) -> String {
+    let mut ret = String::new();
+    for c in val.to_string().chars() {
+        match c {
+            '&' => ret.push_str("&amp;"),
+            '<' => ret.push_str("&lt;"),
+            '>' => ret.push_str("&gt;"),
+            '"' => ret.push_str("&quot;"),
+            '\'' => ret.push_str("&apos;"),
+            c => ret.push(c),
+        }
+    }
+    ret
+}
+
+/// reads the nodes and edges of a GraphML document. node values come from the
+/// `data` of a node key named `val` or `label`, weights from the `data` of an edge
+/// key named `weight` or `label`, other data and elements are skipped. undirected
+/// edges are inserted in both directions like `read_dot`.
+pub fn read_graphml<K, T, W, S>(input: &str) -> Result<Graph<K, T, W, S>, ParseErr>
+where
+    K: Hash + Eq + Clone + FromStr,
+    T: Default + FromStr,
+    W: Default + Clone + FromStr,
+    S: BuildHasher + Default,
+{
+    let mut xml = Xml {
+        cursor: Cursor::new(input),
+    };
+    let mut graph = Graph::default();
+    let mut open: Vec<String> = Vec::new();
+    let mut node_keys: HashSet<String> = HashSet::new();
+    let mut edge_keys: HashSet<String> = HashSet::new();
+    let mut edge_default = true;
+    // element whose data is being read and the text of that data
+    let mut current: Option<Element<K>> = None;
+    let mut data: Option<(Pos, String)> = None;
+
+    while let Some(event) = xml.next()? {
+        match event {
+            Event::Text(pos, text) => {
+                if let Some((start, s)) = data.as_mut() {
+                    if s.is_empty() {
+                        *start = pos;
+                    }
+                    s.push_str(&text);
+                }
+            }
+            Event::Start {
+                pos,
+                name,
+                attrs,
+                empty,
+            } => {
+                let attr = |name: &str| attrs.iter().find(|(n, _, _)| n == name);
+                match name.as_str() {
+                    "key" => {
+                        let id = attr("id").map(|(_, _, v)| v.clone()).unwrap_or_default();
+                        let for_ = attr("for").map(|(_, _, v)| v.as_str());
+                        let attr_name = attr("attr.name").map(|(_, _, v)| v.as_str());
+                        match (for_, attr_name) {
+                            (Some("node"), Some("val" | "label")) => node_keys.insert(id),
+                            (Some("edge"), Some("weight" | "label")) => edge_keys.insert(id),
+                            _ => false,
+                        };
+                    }
+                    "graph" => {
+                        edge_default =
+                            attr("edgedefault").is_none_or(|(_, _, v)| v != "undirected");
+                    }
+                    "node" => {
+                        let (_, pos, id) =
+                            attr("id").ok_or_else(|| missing(pos, "id attribute", "node"))?;
+                        let key: K = pos.key(id)?;
+                        ensure_node(&mut graph, &key);
+                        current = Some(Element::Node(key));
+                    }
+                    "edge" => {
+                        let (_, from_pos, from) = attr("source")
+                            .ok_or_else(|| missing(pos, "source attribute", "edge"))?;
+                        let (_, to_pos, to) = attr("target")
+                            .ok_or_else(|| missing(pos, "target attribute", "edge"))?;
+                        let directed = match attr("directed") {
+                            Some((_, _, v)) => v == "true",
+                            None => edge_default,
+                        };
+                        current = Some(Element::Edge {
+                            from: from_pos.key(from)?,
+                            to: to_pos.key(to)?,
+                            directed,
+                            weight: None,
+                        });
+                    }
+                    "data" => {
+                        let key = attr("key").map(|(_, _, v)| v.as_str());
+                        let wanted = match (&current, key) {
+                            (Some(Element::Node(_)), Some(k)) => node_keys.contains(k),
+                            (Some(Element::Edge { .. }), Some(k)) => edge_keys.contains(k),
+                            _ => false,
+                        };
+                        if wanted {
+                            data = Some((pos, String::new()));
+                        }
+                    }
+                    _ => {}
+                }
+                match empty {
+                    true => end(&mut graph, &name, &mut current, &mut data)?,
+                    false => open.push(name),
+                }
+            }
+            Event::End(pos, name) => {
+                if open.pop().as_ref() != Some(&name) {
+                    return Err(pos.err(ParseErrKind::Unexpected {
+                        expected: "matching end tag",
+                        found: name,
+                    }));
+                }
+                end(&mut graph, &name, &mut current, &mut data)?;
+            }
+        }
+    }
+    match open.is_empty() {
+        true => Ok(graph),
+        false => Err(xml.cursor.unexpected("end tag")),
+    }
+}
+
+enum Element<K> {
+    Node(K),
+    Edge {
+        from: K,
+        to: K,
+        directed: bool,
+        weight: Option<(Pos, String)>,
+    },
+}
+
+/// finishes the data, node or edge element `name`.
+fn end<K, T, W, S>(
+    graph: &mut Graph<K, T, W, S>,
+    name: &str,
+    current: &mut Option<Element<K>>,
+    data: &mut Option<(Pos, String)>,
+) -> Result<(), ParseErr>
+where
+    K: Hash + Eq + Clone,
+    T: Default + FromStr,
+    W: Default + Clone + FromStr,
+    S: BuildHasher + Default,
+{
+    match (name, current.as_mut()) {
+        ("data", Some(Element::Node(key))) => {
+            if let Some((pos, text)) = data.take() {
+                *graph.get_mut(key).unwrap().val_mut() = pos.val(&text)?;
+            }
+        }
+        ("data", Some(Element::Edge { weight, .. })) => {
+            if let Some(text) = data.take() {
+                *weight = Some(text);
+            }
+        }
+        ("node", _) => *current = None,
+        ("edge", _) => {
+            let Some(Element::Edge {
+                from,
+                to,
+                directed,
+                weight,
+            }) = current.take()
+            else {
+                return Ok(());
+            };
+            let weight: W = match weight {
+                Some((pos, text)) => pos.weight(&text)?,
+                None => W::default(),
+            };
+            if !directed && from != to {
+                insert_edge(graph, to.clone(), from.clone(), weight.clone());
+            }
+            insert_edge(graph, from, to, weight);
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn missing(pos: Pos, attr: &'static str, element: &str) -> ParseErr {
+    pos.err(ParseErrKind::Unexpected {
+        expected: attr,
+        found: format!("<{}>", element),
+    })
+}
+
+enum Event {
+    Start {
+        pos: Pos,
+        name: String,
+        /// name, position of the value and unescaped value.
+        attrs: Vec<(String, Pos, String)>,
+        /// `<name/>`, there's no `End` for it.
+        empty: bool,
+    },
+    End(Pos, String),
+    Text(Pos, String),
+}
+
+/// just enough of an XML tokenizer for GraphML, declarations, comments and
+/// doctypes are skipped.
+struct Xml<'a> {
+    cursor: Cursor<'a>,
+}
+
+impl Xml<'_> {
+    fn next(&mut self) -> Result<Option<Event>, ParseErr> {
+        loop {
+            let pos = self.cursor.pos();
+            if self.cursor.peek().is_none() {
+                return Ok(None);
+            }
+            if self.cursor.eat("<!--") {
+                self.skip_past("-->")?;
+            } else if self.cursor.eat("<?") {
+                self.skip_past("?>")?;
+            } else if self.cursor.eat("<!") {
+                self.skip_past(">")?;
+            } else if self.cursor.eat("</") {
+                let name = self.name()?;
+                self.cursor.skip_whitespace();
+                if !self.cursor.eat(">") {
+                    return Err(self.cursor.unexpected(">"));
+                }
+                return Ok(Some(Event::End(pos, name)));
+            } else if self.cursor.eat("<") {
+                return self.start(pos).map(Some);
+            } else {
+                let mut text = String::new();
+                while self.cursor.peek().is_some_and(|c| c != '<') {
+                    text.push(self.char()?);
+                }
+                return Ok(Some(Event::Text(pos, text)));
+            }
+        }
+    }
+
+    fn start(&mut self, pos: Pos) -> Result<Event, ParseErr> {
+        let name = self.name()?;
+        let mut attrs = Vec::new();
+        loop {
+            self.cursor.skip_whitespace();
+            if self.cursor.eat("/>") {
+                return Ok(Event::Start {
+                    pos,
+                    name,
+                    attrs,
+                    empty: true,
+                });
+            }
+            if self.cursor.eat(">") {
+                return Ok(Event::Start {
+                    pos,
+                    name,
+                    attrs,
+                    empty: false,
+                });
+            }
+            let attr = self.name()?;
+            self.cursor.skip_whitespace();
+            if !self.cursor.eat("=") {
+                return Err(self.cursor.unexpected("="));
+            }
+            self.cursor.skip_whitespace();
+            let quote = match self.cursor.peek() {
+                Some(c @ ('"' | '\'')) => c,
+                _ => return Err(self.cursor.unexpected("quoted value")),
+            };
+            self.cursor.bump();
+            let val_pos = self.cursor.pos();
+            let mut val = String::new();
+            loop {
+                match self.cursor.peek() {
+                    Some(c) if c == quote => break,
+                    Some('<') | None => return Err(self.cursor.unexpected("end of value")),
+                    Some(_) => val.push(self.char()?),
+                }
+            }
+            self.cursor.bump();
+            attrs.push((attr, val_pos, val));
+        }
+    }
+
+    fn name(&mut self) -> Result<String, ParseErr> {
+        let mut ret = String::new();
+        while let Some(c) = self.cursor.peek() {
+            if c.is_whitespace() || matches!(c, '/' | '>' | '=' | '<') {
+                break;
+            }
+            ret.push(c);
+            self.cursor.bump();
+        }
+        match ret.is_empty() {
+            true => Err(self.cursor.unexpected("name")),
+            false => Ok(ret),
+        }
+    }
+
+    /// next char with entity and character references resolved.
+    fn char(&mut self) -> Result<char, ParseErr> {
+        let pos = self.cursor.pos();
+        let c = self.cursor.bump().unwrap();
+        if c != '&' {
+            return Ok(c);
+        }
+        let mut entity = String::new();
+        loop {
+            match self.cursor.bump() {
+                Some(';') => break,
+                Some(c) if entity.len() < 10 => entity.push(c),
+                _ => return Err(invalid_entity(pos, entity)),
+            }
+        }
+        let code = match entity.strip_prefix('#') {
+            Some(hex) if hex.starts_with('x') => u32::from_str_radix(&hex[1..], 16).ok(),
+            Some(dec) => dec.parse().ok(),
+            None => match entity.as_str() {
+                "amp" => Some('&' as u32),
+                "lt" => Some('<' as u32),
+                "gt" => Some('>' as u32),
+                "quot" => Some('"' as u32),
+                "apos" => Some('\'' as u32),
+                _ => None,
+            },
+        };
+        code.and_then(char::from_u32)
+            .ok_or_else(|| invalid_entity(pos, entity))
+    }
+
+    fn skip_past(&mut self, end: &'static str) -> Result<(), ParseErr> {
+        while !self.cursor.eat(end) {
+            if self.cursor.bump().is_none() {
+                return Err(self.cursor.unexpected(end));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn invalid_entity(pos: Pos, entity: String) -> ParseErr {
+    pos.err(ParseErrKind::Unexpected {
+        expected: "entity",
+        found: format!("&{}", entity),
+    })
+}
@@ -0,0 +1,179 @@
+use std::{
+    collections::HashSet,
+    fmt::{Display, Write},
+    hash::{BuildHasher, Hash},
+    str::FromStr,
+};
+
+use super::{ensure_node, insert_edge, Cursor, ParseErr, Pos};
+use crate::graph::hash_map::Graph;
+
+/// one `from,to,weight` line per edge and one `key` line per node without edges.
+/// fields with a comma, quote, line break or whitespace at either end are quoted
+/// like RFC 4180, node values aren't written.
+pub fn write_edge_list<K, T, W, S>(
+    graph: &Graph<K, T, W, S>,
+    out: &mut impl Write,
+) -> std::fmt::Result
+where
+    K: Hash + Eq + Clone + Display,
+    W: Display,
+    S: BuildHasher + Default,
+{
+    let mut linked: HashSet<&K> = HashSet::new();
+    for e in graph.edges() {
+        writeln!(out, "{},{},{}", field(e.from), field(e.to), field(e.weight))?;
+        linked.insert(e.from);
+        linked.insert(e.to);
+    }
+    for (key, _) in graph.iter() {
+        if !linked.contains(key) {
+            writeln!(out, "{}", field(key))?;
+        }
+    }
+    Ok(())
+}
+
+fn field(val: impl Display) -> String {
+    let s = val.to_string();
+    let padded = s.starts_with(char::is_whitespace) || s.ends_with(char::is_whitespace);
+    if !padded && !s.contains([',', '"', '\n', '\r']) {
+        return s;
+    }
+    format!("\"{}\"", s.replace('"', "\"\""))
+}
+
+/// reads the output of `write_edge_list`. a line with one field is a node, with
+/// two fields an edge of `W::default()` weight and with three a weighted edge.
+/// empty lines are skipped and unquoted fields are trimmed.
+pub fn read_edge_list<K, T, W, S>(input: &str) -> Result<Graph<K, T, W, S>, ParseErr>
+where
+    K: Hash + Eq + Clone + FromStr,
+    T: Default,
+    W: Default + FromStr,
+    S: BuildHasher + Default,
+{
+    let mut graph = Graph::default();
+    let mut cursor = Cursor::new(input);
+    while cursor.peek().is_some() {
+        if cursor.eat("\n") || cursor.eat("\r\n") {
+            continue;
+        }
+        let mut fields: Vec<(Pos, String)> = Vec::with_capacity(3);
+        loop {
+            fields.push(record_field(&mut cursor)?);
+            if fields.len() == 3 || !cursor.eat(",") {
+                break;
+            }
+        }
+        if !(cursor.peek().is_none() || cursor.eat("\n") || cursor.eat("\r\n")) {
+            return Err(cursor.unexpected("end of line"));
+        }
+
+        let mut fields = fields.into_iter();
+        let (pos, from) = fields.next().unwrap();
+        let from: K = pos.key(&from)?;
+        let Some((pos, to)) = fields.next() else {
+            ensure_node(&mut graph, &from);
+            continue;
+        };
+        let to: K = pos.key(&to)?;
+        let weight = match fields.next() {
+            Some((pos, weight)) => pos.weight(&weight)?,
+            None => W::default(),
+        };
+        insert_edge(&mut graph, from, to, weight);
+    }
+    Ok(graph)
+}
+
+fn record_field(cursor: &mut Cursor) -> Result<(Pos, String), ParseErr> {
+    let pos = cursor.pos();
+    let mut ret = String::new();
+    if !cursor.eat("\"") {
+        while let Some(c) = cursor.peek() {
+            if matches!(c, ',' | '\n' | '\r') {
+                break;
+            }
+            ret.push(c);
+            cursor.bump();
+        }
+        return Ok((pos, ret.trim().to_string()));
+    }
+    loop {
+        match cursor.bump() {
+            Some('"') if cursor.eat("\"") => ret.push('"'),
+            Some('"') => return Ok((pos, ret)),
+            Some(c) => ret.push(c),
+            None => return Err(cursor.unexpected("\"")),
+        }
+    }
+}
+
+/// one line per node, its key followed by the keys of its neighbors separated by
+/// spaces. node values and weights aren't written, keys must not contain whitespace.
+pub fn write_adjacency_list<K, T, W, S>(
+    graph: &Graph<K, T, W, S>,
+    out: &mut impl Write,
+) -> std::fmt::Result
+where
+    K: Hash + Eq + Clone + Display,
+    S: BuildHasher + Default,
+{
+    for (key, _) in graph.iter() {
+        write!(out, "{}", key)?;
+        for (neib, _) in graph.out_neighbors(key).unwrap() {
+            write!(out, " {}", neib)?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// reads the output of `write_adjacency_list`, every edge gets `W::default()`.
+/// empty lines and lines starting with `#` are skipped.
+pub fn read_adjacency_list<K, T, W, S>(input: &str) -> Result<Graph<K, T, W, S>, ParseErr>
+where
+    K: Hash + Eq + Clone + FromStr,
+    T: Default,
+    W: Default,
+    S: BuildHasher + Default,
+{
+    let mut graph = Graph::default();
+    for (i, line) in input.lines().enumerate() {
+        if line.trim_start().starts_with('#') {
+            continue;
+        }
+        let mut words = words(line).map(|(col, word)| {
+            let pos = Pos { line: i + 1, col };
+            pos.key::<K>(word)
+        });
+        let Some(key) = words.next() else {
+            continue;
+        };
+        let key = key?;
+        ensure_node(&mut graph, &key);
+        for neib in words {
+            insert_edge(&mut graph, key.clone(), neib?, W::default());
+        }
+    }
+    Ok(graph)
+}
+
+/// whitespace separated words of `line` with the 1-based column of their first char.
+fn words(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut col = 0;
+    let mut rest = line;
+    std::iter::from_fn(move || {
+        let start = rest.find(|c: char| !c.is_whitespace())?;
+        col += rest[..start].chars().count();
+        let end = rest[start..]
+            .find(char::is_whitespace)
+            .map_or(rest.len(), |e| start + e);
+        let word = &rest[start..end];
+        let ret = (col + 1, word);
+        col += word.chars().count();
+        rest = &rest[end..];
+        Some(ret)
+    })
+}
@@ -0,0 +1,188 @@
+//! readers and writers for `hash_map::Graph` in Graphviz DOT, GraphML, CSV edge
+//! list and whitespace separated adjacency list formats.
+//!
+//! keys, node values and weights are written with `Display` and read with `FromStr`.
+//! readers create missing nodes with `T::default()` and edges without a weight get
+//! `W::default()`.
+
+mod dot;
+mod graphml;
+mod list;
+
+pub use dot::{read_dot, write_dot, write_dot_undirected};
+pub use graphml::{read_graphml, write_graphml, write_graphml_undirected};
+pub use list::{read_adjacency_list, read_edge_list, write_adjacency_list, write_edge_list};
+
+use std::{
+    fmt::{Display, Formatter},
+    hash::{BuildHasher, Hash},
+    str::{Chars, FromStr},
+};
+
+use super::hash_map::Graph;
+
+/// error of a reader, `line` and `col` are 1-based and `col` counts chars.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseErr {
+    pub line: usize,
+    pub col: usize,
+    pub kind: ParseErrKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseErrKind {
+    UnexpectedEof,
+    Unexpected {
+        expected: &'static str,
+        found: String,
+    },
+    /// `FromStr` of the key failed on the text.
+    InvalidKey(String),
+    /// `FromStr` of the node value failed on the text.
+    InvalidVal(String),
+    /// `FromStr` of the weight failed on the text.
+    InvalidWeight(String),
+}
+
+impl Display for ParseErr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.col)?;
+        match &self.kind {
+            ParseErrKind::UnexpectedEof => write!(f, "unexpected end of input"),
+            ParseErrKind::Unexpected { expected, found } => {
+                write!(f, "expected {}, found {:?}", expected, found)
+            }
+            ParseErrKind::InvalidKey(s) => write!(f, "invalid key {:?}", s),
+            ParseErrKind::InvalidVal(s) => write!(f, "invalid node value {:?}", s),
+            ParseErrKind::InvalidWeight(s) => write!(f, "invalid weight {:?}", s),
+        }
+    }
+}
+
+impl std::error::Error for ParseErr {}
+
+/// chars of the input with the position of the next one.
+struct Cursor<'a> {
+    chars: Chars<'a>,
+    line: usize,
+    col: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            chars: input.chars(),
+            line: 1,
+            col: 1,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.clone().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.col = 1;
+        } else {
+            self.col += 1;
+        }
+        Some(c)
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        self.chars.as_str().starts_with(s)
+    }
+
+    /// consumes `s` if the input starts with it.
+    fn eat(&mut self, s: &str) -> bool {
+        if !self.starts_with(s) {
+            return false;
+        }
+        for _ in s.chars() {
+            self.bump();
+        }
+        true
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn pos(&self) -> Pos {
+        Pos {
+            line: self.line,
+            col: self.col,
+        }
+    }
+
+    /// `Unexpected` at the next char or `UnexpectedEof` at the end of the input.
+    fn unexpected(&self, expected: &'static str) -> ParseErr {
+        match self.peek() {
+            Some(c) => self.pos().err(ParseErrKind::Unexpected {
+                expected,
+                found: c.to_string(),
+            }),
+            None => self.pos().err(ParseErrKind::UnexpectedEof),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pos {
+    line: usize,
+    col: usize,
+}
+
+impl Pos {
+    fn err(self, kind: ParseErrKind) -> ParseErr {
+        ParseErr {
+            line: self.line,
+            col: self.col,
+            kind,
+        }
+    }
+
+    fn key<K: FromStr>(self, s: &str) -> Result<K, ParseErr> {
+        s.parse()
+            .map_err(|_| self.err(ParseErrKind::InvalidKey(s.to_string())))
+    }
+
+    fn val<T: FromStr>(self, s: &str) -> Result<T, ParseErr> {
+        s.parse()
+            .map_err(|_| self.err(ParseErrKind::InvalidVal(s.to_string())))
+    }
+
+    fn weight<W: FromStr>(self, s: &str) -> Result<W, ParseErr> {
+        s.parse()
+            .map_err(|_| self.err(ParseErrKind::InvalidWeight(s.to_string())))
+    }
+}
+
+/// inserts `key` with `T::default()` unless it's already in the graph.
+fn ensure_node<K, T, W, S>(graph: &mut Graph<K, T, W, S>, key: &K)
+where
+    K: Hash + Eq + Clone,
+    T: Default,
+    S: BuildHasher + Default,
+{
+    if !graph.contains(key) {
+        graph.insert_node(key.clone(), T::default());
+    }
+}
+
+/// inserts the edge and its ends if they're missing.
+fn insert_edge<K, T, W, S>(graph: &mut Graph<K, T, W, S>, from: K, to: K, weight: W)
+where
+    K: Hash + Eq + Clone,
+    T: Default,
+    S: BuildHasher + Default,
+{
+    ensure_node(graph, &from);
+    ensure_node(graph, &to);
+    graph.insert_edge(from, to, weight).unwrap();
+}
@@ -0,0 +1,325 @@
+//! adjacency-matrix graphs of the nodes `0..nodes_len` stored on the heap, the
+//! number of nodes can change at runtime. removing a node moves every later
+//! node down by one index.
+
+use crate::{
+    graph::{
+        algo::{self, Bfs, Dfs},
+        hash_map::EdgeErr,
+    },
+    matrix::{
+        bit::{BitMatrix, Ones},
+        vec::Matrix,
+    },
+};
+
+use super::Edge;
+
+/// directed weighted graph backed by a `matrix::vec::Matrix`.
+#[derive(Debug, PartialEq)]
+pub struct Graph<W: Copy> {
+    matrix: Matrix<Option<W>>,
+}
+
+/// nodes `from` has an edge to in increasing order.
+pub struct Neighbors<'a, W> {
+    matrix: &'a Matrix<Option<W>>,
+    from: usize,
+    next: usize,
+}
+
+impl<'a, W: Copy> Iterator for Neighbors<'a, W> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.from >= self.matrix.rows_len() {
+            return None;
+        }
+        while self.next < self.matrix.cols_len() {
+            let to = self.next;
+            self.next += 1;
+            if self.matrix.get(self.from, to).is_some_and(|w| w.is_some()) {
+                return Some(to);
+            }
+        }
+        None
+    }
+}
+
+fn check_ends(nodes_len: usize, from: usize, to: usize) -> Result<(), EdgeErr> {
+    if to >= nodes_len {
+        Err(EdgeErr::ToNone)
+    } else if from >= nodes_len {
+        Err(EdgeErr::FromNone)
+    } else {
+        Ok(())
+    }
+}
+
+impl<W: Copy> Graph<W> {
+    pub fn new() -> Self {
+        Self::with_nodes(0)
+    }
+
+    /// graph of the nodes `0..nodes_len` without edges.
+    pub fn with_nodes(nodes_len: usize) -> Self {
+        Self {
+            matrix: Matrix::from_elem(nodes_len, nodes_len, None),
+        }
+    }
+
+    pub fn nodes_len(&self) -> usize {
+        self.matrix.rows_len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes_len() == 0
+    }
+
+    pub fn edges_len(&self) -> usize {
+        (0..self.nodes_len())
+            .map(|from| self.neighbors(from).count())
+            .sum()
+    }
+
+    /// grows the matrix by a row and a column, O(nodes_len^2).
+    ///
+    /// # Returns
+    /// index of the new node.
+    pub fn add_node(&mut self) -> usize {
+        self.matrix.push_row_col(None);
+        self.nodes_len() - 1
+    }
+
+    /// removes the node and its edges, every later node moves down by one index.
+    ///
+    /// # Returns
+    /// false if node >= nodes_len.
+    pub fn remove_node(&mut self, node: usize) -> bool {
+        if node >= self.nodes_len() {
+            return false;
+        }
+        self.matrix.remove_row_col(node);
+        true
+    }
+
+    pub fn contains_edge(&self, from: usize, to: usize) -> bool {
+        self.get_edge(from, to).is_some()
+    }
+
+    /// # Returns
+    /// None if there's no edge `from -> to` or either end >= nodes_len.
+    pub fn get_edge(&self, from: usize, to: usize) -> Option<&W> {
+        check_ends(self.nodes_len(), from, to).ok()?;
+        self.matrix.get(from, to)?.as_ref()
+    }
+
+    pub fn get_edge_mut(&mut self, from: usize, to: usize) -> Option<&mut W> {
+        check_ends(self.nodes_len(), from, to).ok()?;
+        self.matrix.get_mut(from, to)?.as_mut()
+    }
+
+    /// # Returns
+    /// old weight if there was an edge `from -> to`.
+    ///
+    /// # Error
+    /// if to >= nodes_len returns `Err(EdgeErr::ToNone)`.
+    /// if from >= nodes_len returns `Err(EdgeErr::FromNone)`.
+    pub fn insert_edge(&mut self, from: usize, to: usize, weight: W) -> Result<Option<W>, EdgeErr> {
+        check_ends(self.nodes_len(), from, to)?;
+        let slot = unsafe { self.matrix.get_unchecked_mut(from, to) };
+        Ok(slot.replace(weight))
+    }
+
+    /// # Returns
+    /// weight of the removed edge, None if there was no edge `from -> to`.
+    ///
+    /// # Error
+    /// if to >= nodes_len returns `Err(EdgeErr::ToNone)`.
+    /// if from >= nodes_len returns `Err(EdgeErr::FromNone)`.
+    pub fn remove_edge(&mut self, from: usize, to: usize) -> Result<Option<W>, EdgeErr> {
+        check_ends(self.nodes_len(), from, to)?;
+        let slot = unsafe { self.matrix.get_unchecked_mut(from, to) };
+        Ok(slot.take())
+    }
+
+    pub fn edges(&self) -> Vec<Edge<W>> {
+        let mut edges = Vec::new();
+        for from in 0..self.nodes_len() {
+            for to in self.neighbors(from) {
+                edges.push(Edge::new(from, to, *self.get_edge(from, to).unwrap()));
+            }
+        }
+        edges
+    }
+
+    /// nodes `from` has an edge to, empty if from >= nodes_len.
+    pub fn neighbors(&self, from: usize) -> Neighbors<'_, W> {
+        Neighbors {
+            matrix: &self.matrix,
+            from,
+            next: 0,
+        }
+    }
+
+    /// # Returns
+    /// number of edges out of `from`, None if from >= nodes_len.
+    pub fn out_degree(&self, from: usize) -> Option<usize> {
+        (from < self.nodes_len()).then(|| self.neighbors(from).count())
+    }
+
+    /// # Returns
+    /// number of edges into `to`, None if to >= nodes_len.
+    pub fn in_degree(&self, to: usize) -> Option<usize> {
+        (to < self.nodes_len()).then(|| {
+            (0..self.nodes_len())
+                .filter(|from| self.contains_edge(*from, to))
+                .count()
+        })
+    }
+
+    /// # Panics
+    /// if start >= nodes_len
+    pub fn dfs_iter(&self, start: usize) -> Dfs<&Self> {
+        assert!(start < self.nodes_len());
+        algo::dfs(self, start)
+    }
+
+    /// # Panics
+    /// if start >= nodes_len
+    pub fn bfs_iter(&self, start: usize) -> Bfs<&Self> {
+        assert!(start < self.nodes_len());
+        algo::bfs(self, start)
+    }
+}
+
+impl<W: Copy> Default for Graph<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// directed unweighted graph backed by a `matrix::bit::BitMatrix`, one bit per
+/// possible edge.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitGraph {
+    matrix: BitMatrix,
+}
+
+impl BitGraph {
+    pub fn new() -> Self {
+        Self::with_nodes(0)
+    }
+
+    /// graph of the nodes `0..nodes_len` without edges.
+    pub fn with_nodes(nodes_len: usize) -> Self {
+        Self {
+            matrix: BitMatrix::new(nodes_len, nodes_len),
+        }
+    }
+
+    pub fn nodes_len(&self) -> usize {
+        self.matrix.rows_len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes_len() == 0
+    }
+
+    pub fn edges_len(&self) -> usize {
+        self.matrix.count_ones()
+    }
+
+    /// # Returns
+    /// index of the new node.
+    pub fn add_node(&mut self) -> usize {
+        self.matrix.push_row();
+        self.matrix.push_col();
+        self.nodes_len() - 1
+    }
+
+    /// removes the node and its edges, every later node moves down by one index.
+    ///
+    /// # Returns
+    /// false if node >= nodes_len.
+    pub fn remove_node(&mut self, node: usize) -> bool {
+        if node >= self.nodes_len() {
+            return false;
+        }
+        self.matrix.remove_row(node);
+        self.matrix.remove_col(node);
+        true
+    }
+
+    pub fn contains_edge(&self, from: usize, to: usize) -> bool {
+        self.matrix.get(from, to).unwrap_or(false)
+    }
+
+    /// # Returns
+    /// true if the edge is new.
+    ///
+    /// # Error
+    /// if to >= nodes_len returns `Err(EdgeErr::ToNone)`.
+    /// if from >= nodes_len returns `Err(EdgeErr::FromNone)`.
+    pub fn insert_edge(&mut self, from: usize, to: usize) -> Result<bool, EdgeErr> {
+        check_ends(self.nodes_len(), from, to)?;
+        Ok(!self.matrix.set(from, to, true).unwrap())
+    }
+
+    /// # Returns
+    /// true if there was an edge `from -> to`.
+    ///
+    /// # Error
+    /// if to >= nodes_len returns `Err(EdgeErr::ToNone)`.
+    /// if from >= nodes_len returns `Err(EdgeErr::FromNone)`.
+    pub fn remove_edge(&mut self, from: usize, to: usize) -> Result<bool, EdgeErr> {
+        check_ends(self.nodes_len(), from, to)?;
+        Ok(self.matrix.set(from, to, false).unwrap())
+    }
+
+    /// nodes `from` has an edge to in increasing order, empty if from >= nodes_len.
+    pub fn neighbors(&self, from: usize) -> Ones<'_> {
+        self.matrix.row_ones(from)
+    }
+
+    /// # Returns
+    /// number of edges out of `from`, None if from >= nodes_len.
+    pub fn out_degree(&self, from: usize) -> Option<usize> {
+        (from < self.nodes_len()).then(|| self.matrix.row_count_ones(from))
+    }
+
+    /// # Returns
+    /// number of edges into `to`, None if to >= nodes_len.
+    pub fn in_degree(&self, to: usize) -> Option<usize> {
+        (to < self.nodes_len()).then(|| {
+            (0..self.nodes_len())
+                .filter(|from| self.contains_edge(*from, to))
+                .count()
+        })
+    }
+
+    /// # Panics
+    /// if start >= nodes_len
+    pub fn dfs_iter(&self, start: usize) -> Dfs<&Self> {
+        assert!(start < self.nodes_len());
+        algo::dfs(self, start)
+    }
+
+    /// # Panics
+    /// if start >= nodes_len
+    pub fn bfs_iter(&self, start: usize) -> Bfs<&Self> {
+        assert!(start < self.nodes_len());
+        algo::bfs(self, start)
+    }
+}
+
+impl<W: Copy> From<&Graph<W>> for BitGraph {
+    /// keeps the edges and drops their weights.
+    fn from(value: &Graph<W>) -> Self {
+        let mut ret = Self::with_nodes(value.nodes_len());
+        for edge in value.edges() {
+            ret.insert_edge(edge.from, edge.to).unwrap();
+        }
+        ret
+    }
+}
@@ -0,0 +1,468 @@
+pub mod dynamic;
+
+use std::{collections::HashMap, hash::Hash, ops::Add};
+
+use crate::{
+    graph::{
+        algo::{self, Bfs, Dfs},
+        hash_map::{self, EdgeErr},
+        union_find::DisjointSet,
+    },
+    matrix::array::Matrix,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge<W> {
+    pub from: usize,
+    pub to: usize,
+    pub weight: W,
+}
+
+impl<W> Edge<W> {
+    pub fn new(from: usize, to: usize, weight: W) -> Self {
+        Edge { from, to, weight }
+    }
+}
+
+/// nodes `from` has an edge to in increasing order.
+pub struct Neighbors<'a, const L: usize, W> {
+    matrix: &'a Matrix<Option<W>, L, L>,
+    from: usize,
+    next: usize,
+}
+
+impl<'a, const L: usize, W: Copy> Iterator for Neighbors<'a, L, W> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next < L {
+            let to = self.next;
+            self.next += 1;
+            if self.matrix.get(self.from, to).is_some_and(|w| w.is_some()) {
+                return Some(to);
+            }
+        }
+        None
+    }
+}
+
+fn check_ends<const L: usize>(from: usize, to: usize) -> Result<(), EdgeErr> {
+    if to >= L {
+        Err(EdgeErr::ToNone)
+    } else if from >= L {
+        Err(EdgeErr::FromNone)
+    } else {
+        Ok(())
+    }
+}
+
+pub mod directed {
+    use super::*;
+
+    /// directed graph of the nodes `0..L`, `matrix[from][to]` is the weight of the edge `from -> to`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Graph<const L: usize, W: Copy> {
+        matrix: Matrix<Option<W>, L, L>,
+    }
+
+    impl<const L: usize, W: Copy> Graph<L, W> {
+        pub fn new() -> Self {
+            Self {
+                matrix: Matrix::new([[None; L]; L]),
+            }
+        }
+
+        pub fn contains_edge(&self, from: usize, to: usize) -> bool {
+            self.get_edge(from, to).is_some()
+        }
+
+        /// # Returns
+        /// None if there's no edge `from -> to` or from >= L || to >= L.
+        pub fn get_edge(&self, from: usize, to: usize) -> Option<&W> {
+            self.matrix.get(from, to)?.as_ref()
+        }
+
+        pub fn get_edge_mut(&mut self, from: usize, to: usize) -> Option<&mut W> {
+            self.matrix.get_mut(from, to)?.as_mut()
+        }
+
+        /// # Panics
+        /// if from >= L || to >= L or there's no edge `from -> to`.
+        pub fn get_edge_unwrap(&self, from: usize, to: usize) -> &W {
+            self.get_edge(from, to).unwrap()
+        }
+
+        /// # Panics
+        /// if from >= L || to >= L or there's no edge `from -> to`.
+        pub fn get_edge_mut_unwrap(&mut self, from: usize, to: usize) -> &mut W {
+            self.get_edge_mut(from, to).unwrap()
+        }
+
+        /// # Returns
+        /// old weight if there was an edge `from -> to`.
+        ///
+        /// # Error
+        /// if to >= L returns `Err(EdgeErr::ToNone)`.
+        /// if from >= L returns `Err(EdgeErr::FromNone)`.
+        pub fn insert_edge(
+            &mut self,
+            from: usize,
+            to: usize,
+            weight: W,
+        ) -> Result<Option<W>, EdgeErr> {
+            check_ends::<L>(from, to)?;
+            let slot = unsafe { self.matrix.get_unchecked_mut(from, to) };
+            Ok(slot.replace(weight))
+        }
+
+        /// # Returns
+        /// weight of the removed edge, None if there was no edge `from -> to`.
+        ///
+        /// # Error
+        /// if to >= L returns `Err(EdgeErr::ToNone)`.
+        /// if from >= L returns `Err(EdgeErr::FromNone)`.
+        pub fn remove_edge(&mut self, from: usize, to: usize) -> Result<Option<W>, EdgeErr> {
+            check_ends::<L>(from, to)?;
+            let slot = unsafe { self.matrix.get_unchecked_mut(from, to) };
+            Ok(slot.take())
+        }
+
+        pub fn edges(&self) -> Vec<Edge<W>> {
+            let mut edges = Vec::new();
+            for from in 0..L {
+                for to in self.neighbors(from) {
+                    edges.push(Edge::new(from, to, *self.get_edge_unwrap(from, to)));
+                }
+            }
+            edges
+        }
+
+        pub fn edges_len(&self) -> usize {
+            (0..L).map(|from| self.neighbors(from).count()).sum()
+        }
+
+        /// nodes `from` has an edge to, empty if from >= L.
+        pub fn neighbors(&self, from: usize) -> Neighbors<'_, L, W> {
+            Neighbors {
+                matrix: &self.matrix,
+                from,
+                next: 0,
+            }
+        }
+
+        /// # Returns
+        /// number of edges out of `from`, None if from >= L.
+        pub fn out_degree(&self, from: usize) -> Option<usize> {
+            (from < L).then(|| self.neighbors(from).count())
+        }
+
+        /// # Returns
+        /// number of edges into `to`, None if to >= L.
+        pub fn in_degree(&self, to: usize) -> Option<usize> {
+            (to < L).then(|| (0..L).filter(|from| self.contains_edge(*from, to)).count())
+        }
+
+        /// # Panics
+        /// if start >= L
+        pub fn dfs_iter(&self, start: usize) -> Dfs<&Self> {
+            assert!(start < L);
+            algo::dfs(self, start)
+        }
+
+        /// # Panics
+        /// if start >= L
+        pub fn bfs_iter(&self, start: usize) -> Bfs<&Self> {
+            assert!(start < L);
+            algo::bfs(self, start)
+        }
+
+        /// # Returns
+        /// the graph and the key of every node index, None if `graph` has more than L nodes.
+        pub fn from_graph<K, T>(graph: &hash_map::Graph<K, T, W>) -> Option<(Self, Vec<K>)>
+        where
+            K: Hash + Eq + Clone,
+        {
+            if graph.nodes_len() > L {
+                return None;
+            }
+            let keys: Vec<&K> = graph.iter().map(|(k, _)| k).collect();
+            let index: HashMap<&K, usize> = keys.iter().enumerate().map(|(i, k)| (*k, i)).collect();
+            let mut ret = Self::new();
+            for (from, key) in keys.iter().enumerate() {
+                for (neib, weight) in graph.get(key).unwrap().neighbors() {
+                    if let Some(&to) = index.get(neib) {
+                        ret.insert_edge(from, to, *weight).unwrap();
+                    }
+                }
+            }
+            Some((ret, keys.into_iter().cloned().collect()))
+        }
+    }
+
+    impl<const L: usize, W: Copy> Default for Graph<L, W> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    /// nodes are `0..L` with `()` values.
+    impl<const L: usize, W: Copy> From<&Graph<L, W>> for hash_map::Graph<usize, (), W> {
+        fn from(value: &Graph<L, W>) -> Self {
+            let mut ret = hash_map::Graph::new();
+            for i in 0..L {
+                ret.insert_node(i, ());
+            }
+            for edge in value.edges() {
+                ret.insert_edge(edge.from, edge.to, edge.weight).unwrap();
+            }
+            ret
+        }
+    }
+}
+
+pub mod undirected {
+    use super::*;
+
+    /// undirected graph of the nodes `0..L`, the matrix is kept symmetric.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Graph<const L: usize, W: Copy> {
+        matrix: Matrix<Option<W>, L, L>,
+    }
+
+    impl<const L: usize, W: Copy> Graph<L, W> {
+        pub fn new() -> Self {
+            Self {
+                matrix: Matrix::new([[None; L]; L]),
+            }
+        }
+
+        pub fn contains_edge(&self, x: usize, y: usize) -> bool {
+            self.get_edge(x, y).is_some()
+        }
+
+        /// # Returns
+        /// None if there's no edge between `x` and `y` or x >= L || y >= L.
+        pub fn get_edge(&self, x: usize, y: usize) -> Option<&W> {
+            self.matrix.get(x, y)?.as_ref()
+        }
+
+        /// # Returns
+        /// old weight if there was an edge between `x` and `y`.
+        ///
+        /// # Error
+        /// if y >= L returns `Err(EdgeErr::ToNone)`.
+        /// if x >= L returns `Err(EdgeErr::FromNone)`.
+        pub fn insert_edge(&mut self, x: usize, y: usize, weight: W) -> Result<Option<W>, EdgeErr> {
+            check_ends::<L>(x, y)?;
+            let old = unsafe {
+                let old = self.matrix.get_unchecked_mut(x, y).replace(weight);
+                *self.matrix.get_unchecked_mut(y, x) = Some(weight);
+                old
+            };
+            Ok(old)
+        }
+
+        /// # Returns
+        /// weight of the removed edge, None if there was no edge between `x` and `y`.
+        ///
+        /// # Error
+        /// if y >= L returns `Err(EdgeErr::ToNone)`.
+        /// if x >= L returns `Err(EdgeErr::FromNone)`.
+        pub fn remove_edge(&mut self, x: usize, y: usize) -> Result<Option<W>, EdgeErr> {
+            check_ends::<L>(x, y)?;
+            let old = unsafe {
+                let old = self.matrix.get_unchecked_mut(x, y).take();
+                *self.matrix.get_unchecked_mut(y, x) = None;
+                old
+            };
+            Ok(old)
+        }
+
+        /// # Panics
+        /// if x >= L || y >= L or there's no edge between `x` and `y`.
+        pub fn get_edge_unwrap(&self, x: usize, y: usize) -> &W {
+            self.get_edge(x, y).unwrap()
+        }
+
+        /// # Returns
+        /// old weight if there was an edge between `x` and `y`.
+        ///
+        /// # Panics
+        /// if x >= L || y >= L
+        pub fn insert_edge_unwrap(&mut self, x: usize, y: usize, weight: W) -> Option<W> {
+            self.insert_edge(x, y, weight).unwrap()
+        }
+
+        /// every edge is reported once with `from <= to`.
+        pub fn edges(&self) -> Vec<Edge<W>> {
+            let mut edges = Vec::new();
+            for x in 0..L {
+                for y in self.neighbors(x).filter(|y| *y >= x) {
+                    edges.push(Edge::new(x, y, *self.get_edge_unwrap(x, y)));
+                }
+            }
+            edges
+        }
+
+        pub fn edges_len(&self) -> usize {
+            (0..L)
+                .map(|x| self.neighbors(x).filter(|y| *y >= x).count())
+                .sum()
+        }
+
+        /// nodes `x` has an edge with, empty if x >= L.
+        pub fn neighbors(&self, x: usize) -> Neighbors<'_, L, W> {
+            Neighbors {
+                matrix: &self.matrix,
+                from: x,
+                next: 0,
+            }
+        }
+
+        /// # Returns
+        /// number of edges touching `x`, a self loop counts twice. None if x >= L.
+        pub fn degree(&self, x: usize) -> Option<usize> {
+            (x < L).then(|| self.neighbors(x).count() + self.contains_edge(x, x) as usize)
+        }
+
+        /// # Panics
+        /// if start >= L
+        pub fn dfs_iter(&self, start: usize) -> Dfs<&Self> {
+            assert!(start < L);
+            algo::dfs(self, start)
+        }
+
+        /// # Panics
+        /// if start >= L
+        pub fn bfs_iter(&self, start: usize) -> Bfs<&Self> {
+            assert!(start < L);
+            algo::bfs(self, start)
+        }
+
+        /// # Returns
+        /// the graph and the key of every node index, None if `graph` has more than L nodes.
+        pub fn from_graph<K, T>(
+            graph: &hash_map::undirected::Graph<K, T, W>,
+        ) -> Option<(Self, Vec<K>)>
+        where
+            K: Hash + Eq + Clone,
+        {
+            if graph.nodes_len() > L {
+                return None;
+            }
+            let keys: Vec<&K> = graph.iter().map(|(k, _)| k).collect();
+            let index: HashMap<&K, usize> = keys.iter().enumerate().map(|(i, k)| (*k, i)).collect();
+            let mut ret = Self::new();
+            for edge in graph.edges() {
+                if let (Some(&x), Some(&y)) = (index.get(edge.from), index.get(edge.to)) {
+                    ret.insert_edge(x, y, *edge.weight).unwrap();
+                }
+            }
+            Some((ret, keys.into_iter().cloned().collect()))
+        }
+    }
+
+    impl<const L: usize, W: Copy> Default for Graph<L, W> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    /// nodes are `0..L` with `()` values.
+    impl<const L: usize, W: Copy> From<&Graph<L, W>> for hash_map::undirected::Graph<usize, (), W> {
+        fn from(value: &Graph<L, W>) -> Self {
+            let mut ret = hash_map::undirected::Graph::new();
+            for i in 0..L {
+                ret.insert_node(i, ());
+            }
+            for edge in value.edges() {
+                ret.insert_edge(edge.from, edge.to, edge.weight).unwrap();
+            }
+            ret
+        }
+    }
+
+    impl<const L: usize, W> Graph<L, W>
+    where
+        W: Copy + Default + Ord + Add<Output = W>,
+    {
+        /// Kruskal's algorithm.
+        ///
+        /// # Returns
+        /// edges of a minimum spanning tree for every connected component and their total weight.
+        pub fn minimum_spanning_forest(&self) -> (Vec<Edge<W>>, W) {
+            let mut edges: Vec<Edge<W>> = self
+                .edges()
+                .into_iter()
+                .filter(|e| e.from != e.to)
+                .collect();
+            edges.sort_by_key(|e| e.weight);
+
+            let mut set = DisjointSet::new(L);
+            let mut forest = Vec::new();
+            let mut total = W::default();
+            for edge in edges {
+                if forest.len() + 1 >= L {
+                    break;
+                }
+                if set.union(edge.from, edge.to) {
+                    total = total + edge.weight;
+                    forest.push(edge);
+                }
+            }
+            (forest, total)
+        }
+
+        /// Prim's algorithm in O(L^2).
+        ///
+        /// # Returns
+        /// edges of a minimum spanning tree for every connected component and their total weight.
+        pub fn minimum_spanning_forest_prim(&self) -> (Vec<Edge<W>>, W) {
+            let mut visited = [false; L];
+            // cheapest known edge into every node from the current tree
+            let mut best: [Option<Edge<W>>; L] = [None; L];
+            let mut forest = Vec::new();
+            let mut total = W::default();
+
+            for start in 0..L {
+                if visited[start] {
+                    continue;
+                }
+                let mut current = start;
+                loop {
+                    visited[current] = true;
+                    for next in self.neighbors(current) {
+                        let weight = *self.get_edge_unwrap(current, next);
+                        if visited[next] {
+                            continue;
+                        }
+                        if best[next].is_none_or(|e| weight < e.weight) {
+                            best[next] = Some(Edge::new(current, next, weight));
+                        }
+                    }
+
+                    let mut min: Option<Edge<W>> = None;
+                    for (next, edge) in best.iter().enumerate() {
+                        if visited[next] {
+                            continue;
+                        }
+                        if let Some(edge) = edge {
+                            if min.is_none_or(|m| edge.weight < m.weight) {
+                                min = Some(*edge);
+                            }
+                        }
+                    }
+                    match min {
+                        Some(edge) => {
+                            total = total + edge.weight;
+                            forest.push(edge);
+                            current = edge.to;
+                        }
+                        None => break,
+                    }
+                }
+            }
+            (forest, total)
+        }
+    }
+}
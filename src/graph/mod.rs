@@ -1,2 +1,13 @@
+pub mod algo;
+pub mod arena;
+pub mod assignment;
+pub mod clique;
+pub mod coloring;
+pub mod csr;
+pub mod generators;
 pub mod hash_map;
-pub mod matrix;
\ No newline at end of file
+pub mod io;
+pub mod matrix;
+pub mod traits;
+pub mod union_find;
+pub mod visit;
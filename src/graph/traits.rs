@@ -0,0 +1,286 @@
+//! traits shared by the graph types so algorithms in `graph::algo` can run on
+//! any of them. the traits are implemented for references to the graphs, e.g.
+//! `&hash_map::Graph` with `&K` node ids or `&matrix::directed::Graph` with
+//! `usize` node ids.
+
+use std::{
+    hash::{BuildHasher, Hash},
+    iter::{Flatten, Map},
+    ops::Range,
+};
+
+use super::{hash_map, matrix};
+use crate::matrix::bit::Ones;
+
+pub trait GraphBase {
+    type NodeId: Copy + Eq + Hash;
+}
+
+pub trait NodeCount: GraphBase {
+    fn node_count(&self) -> usize;
+}
+
+pub trait IntoNodeIdentifiers: GraphBase + Copy {
+    type NodeIdentifiers: Iterator<Item = Self::NodeId>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers;
+}
+
+pub trait IntoNeighbors: GraphBase + Copy {
+    type Neighbors: Iterator<Item = Self::NodeId>;
+
+    /// nodes `node` has an edge to, in an undirected graph every edge is seen from both ends.
+    /// empty if the graph doesn't contain `node`.
+    fn neighbors(self, node: Self::NodeId) -> Self::Neighbors;
+}
+
+pub trait EdgeWeights: GraphBase {
+    type Weight;
+
+    /// # Returns
+    /// weight of the edge `from -> to`, None if there's no such edge.
+    fn edge_weight(&self, from: Self::NodeId, to: Self::NodeId) -> Option<&Self::Weight>;
+}
+
+type Keyed<'a, K, T, W, S> =
+    Map<hash_map::Iter<'a, K, T, W, S>, fn((&'a K, &'a hash_map::Node<K, T, W, S>)) -> &'a K>;
+type NeighborKeys<'a, K, W, S> = Map<
+    Flatten<std::option::IntoIter<hash_map::OutNeighbors<'a, K, W, S>>>,
+    fn((&'a K, &'a W)) -> &'a K,
+>;
+
+fn node_key<'a, K, T, W, S>((key, _): (&'a K, &'a hash_map::Node<K, T, W, S>)) -> &'a K {
+    key
+}
+
+fn neighbor_key<'a, K, W>((key, _): (&'a K, &'a W)) -> &'a K {
+    key
+}
+
+impl<'a, K, T, W, S> GraphBase for &'a hash_map::Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    type NodeId = &'a K;
+}
+
+impl<K, T, W, S> NodeCount for &hash_map::Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    fn node_count(&self) -> usize {
+        self.nodes_len()
+    }
+}
+
+impl<'a, K, T, W, S> IntoNodeIdentifiers for &'a hash_map::Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    type NodeIdentifiers = Keyed<'a, K, T, W, S>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        self.iter().map(node_key)
+    }
+}
+
+impl<'a, K, T, W, S> IntoNeighbors for &'a hash_map::Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    type Neighbors = NeighborKeys<'a, K, W, S>;
+
+    fn neighbors(self, node: &'a K) -> Self::Neighbors {
+        self.out_neighbors(node)
+            .into_iter()
+            .flatten()
+            .map(neighbor_key)
+    }
+}
+
+impl<'a, K, T, W, S> EdgeWeights for &'a hash_map::Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    type Weight = W;
+
+    fn edge_weight(&self, from: &'a K, to: &'a K) -> Option<&W> {
+        self.get(from)?.neighbors().get(to)
+    }
+}
+
+impl<'a, K, T, W, S> GraphBase for &'a hash_map::undirected::Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    type NodeId = &'a K;
+}
+
+impl<K, T, W, S> NodeCount for &hash_map::undirected::Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    fn node_count(&self) -> usize {
+        self.nodes_len()
+    }
+}
+
+impl<'a, K, T, W, S> IntoNodeIdentifiers for &'a hash_map::undirected::Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    type NodeIdentifiers = Keyed<'a, K, T, W, S>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        self.as_directed().node_identifiers()
+    }
+}
+
+impl<'a, K, T, W, S> IntoNeighbors for &'a hash_map::undirected::Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    type Neighbors = NeighborKeys<'a, K, W, S>;
+
+    fn neighbors(self, node: &'a K) -> Self::Neighbors {
+        self.as_directed().neighbors(node)
+    }
+}
+
+impl<'a, K, T, W, S> EdgeWeights for &'a hash_map::undirected::Graph<K, T, W, S>
+where
+    K: Hash + Eq + Clone,
+    S: BuildHasher + Default,
+{
+    type Weight = W;
+
+    fn edge_weight(&self, from: &'a K, to: &'a K) -> Option<&W> {
+        self.get(from)?.neighbors().get(to)
+    }
+}
+
+/// implements the traits for a matrix graph.
+macro_rules! impl_matrix_traits {
+    ($graph:ty) => {
+        impl<const L: usize, W> GraphBase for &$graph
+        where
+            W: Copy,
+        {
+            type NodeId = usize;
+        }
+
+        impl<const L: usize, W> NodeCount for &$graph
+        where
+            W: Copy,
+        {
+            fn node_count(&self) -> usize {
+                L
+            }
+        }
+
+        impl<const L: usize, W> IntoNodeIdentifiers for &$graph
+        where
+            W: Copy,
+        {
+            type NodeIdentifiers = Range<usize>;
+
+            fn node_identifiers(self) -> Self::NodeIdentifiers {
+                0..L
+            }
+        }
+
+        impl<'a, const L: usize, W> IntoNeighbors for &'a $graph
+        where
+            W: Copy,
+        {
+            type Neighbors = matrix::Neighbors<'a, L, W>;
+
+            fn neighbors(self, node: usize) -> Self::Neighbors {
+                <$graph>::neighbors(self, node)
+            }
+        }
+
+        impl<const L: usize, W> EdgeWeights for &$graph
+        where
+            W: Copy,
+        {
+            type Weight = W;
+
+            fn edge_weight(&self, from: usize, to: usize) -> Option<&W> {
+                self.get_edge(from, to)
+            }
+        }
+    };
+}
+
+impl_matrix_traits!(matrix::directed::Graph<L, W>);
+impl_matrix_traits!(matrix::undirected::Graph<L, W>);
+
+impl<W: Copy> GraphBase for &matrix::dynamic::Graph<W> {
+    type NodeId = usize;
+}
+
+impl<W: Copy> NodeCount for &matrix::dynamic::Graph<W> {
+    fn node_count(&self) -> usize {
+        self.nodes_len()
+    }
+}
+
+impl<W: Copy> IntoNodeIdentifiers for &matrix::dynamic::Graph<W> {
+    type NodeIdentifiers = Range<usize>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        0..self.nodes_len()
+    }
+}
+
+impl<'a, W: Copy> IntoNeighbors for &'a matrix::dynamic::Graph<W> {
+    type Neighbors = matrix::dynamic::Neighbors<'a, W>;
+
+    fn neighbors(self, node: usize) -> Self::Neighbors {
+        matrix::dynamic::Graph::neighbors(self, node)
+    }
+}
+
+impl<W: Copy> EdgeWeights for &matrix::dynamic::Graph<W> {
+    type Weight = W;
+
+    fn edge_weight(&self, from: usize, to: usize) -> Option<&W> {
+        self.get_edge(from, to)
+    }
+}
+
+impl GraphBase for &matrix::dynamic::BitGraph {
+    type NodeId = usize;
+}
+
+impl NodeCount for &matrix::dynamic::BitGraph {
+    fn node_count(&self) -> usize {
+        self.nodes_len()
+    }
+}
+
+impl IntoNodeIdentifiers for &matrix::dynamic::BitGraph {
+    type NodeIdentifiers = Range<usize>;
+
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        0..self.nodes_len()
+    }
+}
+
+impl<'a> IntoNeighbors for &'a matrix::dynamic::BitGraph {
+    type Neighbors = Ones<'a>;
+
+    fn neighbors(self, node: usize) -> Self::Neighbors {
+        matrix::dynamic::BitGraph::neighbors(self, node)
+    }
+}
@@ -0,0 +1,109 @@
+use std::cmp::Ordering;
+
+/// disjoint set forest over the elements `0..len` with path compression
+/// and union by rank.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    sets_len: usize,
+}
+
+impl DisjointSet {
+    /// every element starts in its own set.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            sets_len: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// number of disjoint sets.
+    pub fn sets_len(&self) -> usize {
+        self.sets_len
+    }
+
+    /// # Returns
+    /// the new element, which is in a set on its own.
+    pub fn push(&mut self) -> usize {
+        let x = self.parent.len();
+        self.parent.push(x);
+        self.rank.push(0);
+        self.sets_len += 1;
+        x
+    }
+
+    /// # Returns
+    /// representative of the set containing `x`.
+    ///
+    /// # Panics
+    /// if x >= len
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut x = x;
+        while self.parent[x] != root {
+            let next = self.parent[x];
+            self.parent[x] = root;
+            x = next;
+        }
+        root
+    }
+
+    /// merges the sets containing `x` and `y`.
+    ///
+    /// # Returns
+    /// false if `x` and `y` were already in the same set.
+    ///
+    /// # Panics
+    /// if x >= len || y >= len
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let (x, y) = (self.find(x), self.find(y));
+        if x == y {
+            return false;
+        }
+        match self.rank[x].cmp(&self.rank[y]) {
+            Ordering::Less => self.parent[x] = y,
+            Ordering::Greater => self.parent[y] = x,
+            Ordering::Equal => {
+                self.parent[y] = x;
+                self.rank[x] += 1;
+            }
+        }
+        self.sets_len -= 1;
+        true
+    }
+
+    /// # Panics
+    /// if x >= len || y >= len
+    pub fn is_same_set(&mut self, x: usize, y: usize) -> bool {
+        self.find(x) == self.find(y)
+    }
+
+    /// # Returns
+    /// every set as a list of its elements.
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut index = vec![usize::MAX; self.len()];
+        let mut sets: Vec<Vec<usize>> = Vec::with_capacity(self.sets_len);
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if index[root] == usize::MAX {
+                index[root] = sets.len();
+                sets.push(Vec::new());
+            }
+            sets[index[root]].push(x);
+        }
+        sets
+    }
+}
@@ -0,0 +1,171 @@
+//! depth first traversal that reports its events to a visitor, written against
+//! the traits in `graph::traits`.
+
+use std::collections::HashMap;
+
+use super::traits::IntoNeighbors;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DfsEvent<N> {
+    /// first time the node is reached, with its depth in the search tree.
+    Discover(N, usize),
+    /// edge to a node that isn't discovered yet, it's discovered next.
+    TreeEdge(N, N),
+    /// edge to a node on the current path. in an undirected graph the edge back
+    /// to the parent is reported as one too.
+    BackEdge(N, N),
+    /// edge to a finished node, a cross or a forward edge.
+    CrossEdge(N, N),
+    /// every neighbor of the node has been handled.
+    Finish(N),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    Continue,
+    /// returned for `Discover` the node's neighbors are skipped, for `TreeEdge`
+    /// the target isn't discovered through it. same as `Continue` for other events.
+    Prune,
+    /// stops the whole search.
+    Break,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Color {
+    /// on the current path.
+    Gray,
+    Finished,
+}
+
+/// node on the current path and its neighbors left to handle, None if they're skipped.
+struct Frame<N, I> {
+    node: N,
+    neibs: Option<I>,
+    depth: usize,
+}
+
+/// depth first search from every node of `starts` that isn't discovered yet.
+/// nodes deeper than `max_depth` aren't discovered. every node is discovered once,
+/// so with a depth limit a node first reached through a long path isn't expanded
+/// even if a shorter one exists, `iddfs` handles that.
+///
+/// # Returns
+/// `Control::Break` if the visitor stopped the search, `Control::Continue` otherwise.
+pub fn depth_first_search<G, I, F>(
+    graph: G,
+    starts: I,
+    max_depth: Option<usize>,
+    mut visitor: F,
+) -> Control
+where
+    G: IntoNeighbors,
+    I: IntoIterator<Item = G::NodeId>,
+    F: FnMut(DfsEvent<G::NodeId>) -> Control,
+{
+    let mut color: HashMap<G::NodeId, Color> = HashMap::new();
+    let mut stack: Vec<Frame<G::NodeId, G::Neighbors>> = Vec::new();
+    let discover = |node, depth, color: &mut HashMap<_, _>, visitor: &mut F| {
+        color.insert(node, Color::Gray);
+        let neibs = match visitor(DfsEvent::Discover(node, depth)) {
+            Control::Break => return None,
+            Control::Prune => None,
+            Control::Continue if max_depth.is_some_and(|max| depth >= max) => None,
+            Control::Continue => Some(graph.neighbors(node)),
+        };
+        Some(Frame { node, neibs, depth })
+    };
+
+    for start in starts {
+        if color.contains_key(&start) {
+            continue;
+        }
+        let Some(frame) = discover(start, 0, &mut color, &mut visitor) else {
+            return Control::Break;
+        };
+        stack.push(frame);
+
+        while let Some(frame) = stack.last_mut() {
+            let (node, depth) = (frame.node, frame.depth);
+            let Some(neib) = frame.neibs.as_mut().and_then(|n| n.next()) else {
+                stack.pop();
+                color.insert(node, Color::Finished);
+                if visitor(DfsEvent::Finish(node)) == Control::Break {
+                    return Control::Break;
+                }
+                continue;
+            };
+            let event = match color.get(&neib) {
+                None => DfsEvent::TreeEdge(node, neib),
+                Some(Color::Gray) => DfsEvent::BackEdge(node, neib),
+                Some(Color::Finished) => DfsEvent::CrossEdge(node, neib),
+            };
+            match (visitor(event), event) {
+                (Control::Break, _) => return Control::Break,
+                (Control::Continue, DfsEvent::TreeEdge(..)) => {
+                    let Some(frame) = discover(neib, depth + 1, &mut color, &mut visitor) else {
+                        return Control::Break;
+                    };
+                    stack.push(frame);
+                }
+                _ => {}
+            }
+        }
+    }
+    Control::Continue
+}
+
+/// iterative deepening depth first search, depth limited searches with limits
+/// 0, 1, ... up to `max_depth` until a node satisfying `is_goal` is found. it finds
+/// the shallowest goal like a breadth first search. the stack is proportional to
+/// the depth but the depth every node was reached at is remembered so nodes of
+/// several paths aren't expanded again, memory is proportional to the nodes visited.
+///
+/// # Returns
+/// nodes of a path from `start` to the shallowest goal, None if there's none
+/// within `max_depth` edges.
+pub fn iddfs<G, F>(
+    graph: G,
+    start: G::NodeId,
+    max_depth: usize,
+    mut is_goal: F,
+) -> Option<Vec<G::NodeId>>
+where
+    G: IntoNeighbors,
+    F: FnMut(G::NodeId) -> bool,
+{
+    if is_goal(start) {
+        return Some(vec![start]);
+    }
+    for limit in 1..=max_depth {
+        // smallest depth every node was reached at in this iteration, a node found
+        // again through a shorter path is expanded again
+        let mut best: HashMap<G::NodeId, usize> = HashMap::from([(start, 0)]);
+        let mut stack = vec![(start, graph.neighbors(start))];
+        let mut cut_off = false;
+
+        while !stack.is_empty() {
+            let depth = stack.len() - 1;
+            let Some(neib) = stack[depth].1.next() else {
+                stack.pop();
+                continue;
+            };
+            if best.get(&neib).is_some_and(|&d| d <= depth + 1) {
+                continue;
+            }
+            best.insert(neib, depth + 1);
+            if is_goal(neib) {
+                let mut path: Vec<G::NodeId> = stack.iter().map(|(node, _)| *node).collect();
+                path.push(neib);
+                return Some(path);
+            }
+            match depth + 1 < limit {
+                true => stack.push((neib, graph.neighbors(neib))),
+                false => cut_off = true,
+            }
+        }
+        if !cut_off {
+            return None;
+        }
+    }
+    None
+}
@@ -0,0 +1,163 @@
+/// matrix of bits, every row is packed into `u64` words. bits past `cols_len`
+/// in the last word of a row are always zero.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitMatrix {
+    words: Vec<u64>,
+    rows_len: usize,
+    cols_len: usize,
+    row_words: usize,
+}
+
+impl BitMatrix {
+    /// `rows_len` x `cols_len` matrix of zeros.
+    pub fn new(rows_len: usize, cols_len: usize) -> Self {
+        let row_words = cols_len.div_ceil(64);
+        Self {
+            words: vec![0; rows_len * row_words],
+            rows_len,
+            cols_len,
+            row_words,
+        }
+    }
+
+    pub fn rows_len(&self) -> usize {
+        self.rows_len
+    }
+
+    pub fn cols_len(&self) -> usize {
+        self.cols_len
+    }
+
+    /// # Returns
+    /// None if row >= rows_len || col >= cols_len.
+    pub fn get(&self, row: usize, col: usize) -> Option<bool> {
+        if row >= self.rows_len || col >= self.cols_len {
+            return None;
+        }
+        let word = self.words[row * self.row_words + col / 64];
+        Some(word >> (col % 64) & 1 == 1)
+    }
+
+    /// # Returns
+    /// old value, None if row >= rows_len || col >= cols_len.
+    pub fn set(&mut self, row: usize, col: usize, val: bool) -> Option<bool> {
+        let old = self.get(row, col)?;
+        let word = &mut self.words[row * self.row_words + col / 64];
+        if val {
+            *word |= 1 << (col % 64);
+        } else {
+            *word &= !(1 << (col % 64));
+        }
+        Some(old)
+    }
+
+    /// columns of the set bits of `row` in increasing order, empty if row >= rows_len.
+    pub fn row_ones(&self, row: usize) -> Ones<'_> {
+        let words = match row < self.rows_len {
+            true => &self.words[row * self.row_words..(row + 1) * self.row_words],
+            false => &[],
+        };
+        Ones {
+            words,
+            index: 0,
+            current: words.first().copied().unwrap_or(0),
+        }
+    }
+
+    /// number of set bits in `row`, 0 if row >= rows_len.
+    pub fn row_count_ones(&self, row: usize) -> usize {
+        if row >= self.rows_len {
+            return 0;
+        }
+        self.words[row * self.row_words..(row + 1) * self.row_words]
+            .iter()
+            .map(|w| w.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// sets every bit of `row` that's set in `other` row.
+    ///
+    /// # Panics
+    /// if row >= rows_len || other >= rows_len
+    pub fn or_row(&mut self, row: usize, other: usize) {
+        assert!(row < self.rows_len && other < self.rows_len);
+        for i in 0..self.row_words {
+            self.words[row * self.row_words + i] |= self.words[other * self.row_words + i];
+        }
+    }
+
+    /// appends a row of zeros.
+    pub fn push_row(&mut self) {
+        self.words.resize(self.words.len() + self.row_words, 0);
+        self.rows_len += 1;
+    }
+
+    /// appends a column of zeros.
+    pub fn push_col(&mut self) {
+        if self.cols_len == self.row_words * 64 {
+            let row_words = self.row_words + 1;
+            let mut words = vec![0; self.rows_len * row_words];
+            for row in 0..self.rows_len {
+                let old = &self.words[row * self.row_words..(row + 1) * self.row_words];
+                words[row * row_words..row * row_words + self.row_words].copy_from_slice(old);
+            }
+            self.words = words;
+            self.row_words = row_words;
+        }
+        self.cols_len += 1;
+    }
+
+    /// removes `row`, later rows move up by one.
+    ///
+    /// # Panics
+    /// if row >= rows_len
+    pub fn remove_row(&mut self, row: usize) {
+        assert!(row < self.rows_len);
+        self.words
+            .drain(row * self.row_words..(row + 1) * self.row_words);
+        self.rows_len -= 1;
+    }
+
+    /// removes `col`, later columns move left by one.
+    ///
+    /// # Panics
+    /// if col >= cols_len
+    pub fn remove_col(&mut self, col: usize) {
+        assert!(col < self.cols_len);
+        let (first, bit) = (col / 64, col % 64);
+        let low = (1u64 << bit) - 1;
+        for row in self.words.chunks_mut(self.row_words) {
+            row[first] = (row[first] & low) | (row[first] >> 1 & !low);
+            for i in first + 1..row.len() {
+                row[i - 1] |= (row[i] & 1) << 63;
+                row[i] >>= 1;
+            }
+        }
+        self.cols_len -= 1;
+    }
+}
+
+/// set bits of one row of a `BitMatrix`.
+pub struct Ones<'a> {
+    words: &'a [u64],
+    index: usize,
+    current: u64,
+}
+
+impl<'a> Iterator for Ones<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            self.index += 1;
+            self.current = *self.words.get(self.index)?;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+        Some(self.index * 64 + bit)
+    }
+}
@@ -1,2 +1,3 @@
 pub mod array;
+pub mod bit;
 pub mod vec;
\ No newline at end of file
@@ -25,19 +25,25 @@ where
     }
 
     pub fn get(&self, row: usize, col: usize) -> Option<&T> {
-        self.vec.get(row * self.col_len + col)
+        if col >= self.row_len {
+            return None;
+        }
+        self.vec.get(row * self.row_len + col)
     }
 
     pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
-        self.vec.get_mut(row * self.col_len + col)
+        if col >= self.row_len {
+            return None;
+        }
+        self.vec.get_mut(row * self.row_len + col)
     }
 
     pub unsafe fn get_unchecked(&self, row: usize, col: usize) -> &T {
-        self.vec.get_unchecked(row * self.col_len + col)
+        self.vec.get_unchecked(row * self.row_len + col)
     }
 
     pub unsafe fn get_unchecked_mut(&mut self, row: usize, col: usize) -> &mut T {
-        self.vec.get_unchecked_mut(row * self.col_len + col)
+        self.vec.get_unchecked_mut(row * self.row_len + col)
     }
 
     pub fn transpose(&self) -> Matrix<T> {
@@ -68,16 +74,70 @@ where
         }
     }
 
+    /// `rows` x `cols` matrix with every element set to `val`, unlike `new` it may be empty.
+    pub fn from_elem(rows: usize, cols: usize, val: T) -> Self {
+        Self {
+            vec: vec![val; rows * cols],
+            col_len: rows,
+            row_len: cols,
+        }
+    }
+
+    pub fn rows_len(&self) -> usize {
+        self.col_len
+    }
+
+    pub fn cols_len(&self) -> usize {
+        self.row_len
+    }
+
+    /// appends a row and a column filled with `val` to a square matrix.
+    ///
+    /// # Panics
+    /// if the matrix isn't square
+    pub fn push_row_col(&mut self, val: T) {
+        let n = self.col_len;
+        assert_eq!(n, self.row_len);
+        self.vec.resize((n + 1) * (n + 1), val);
+        // move rows to their new offsets starting from the last one
+        for i in (0..n).rev() {
+            self.vec.copy_within(i * n..(i + 1) * n, i * (n + 1));
+            self.vec[i * (n + 1) + n] = val;
+        }
+        self.col_len += 1;
+        self.row_len += 1;
+    }
+
+    /// removes row `i` and column `i` of a square matrix, later rows and columns move up by one.
+    ///
+    /// # Panics
+    /// if the matrix isn't square or i >= its size
+    pub fn remove_row_col(&mut self, i: usize) {
+        let n = self.col_len;
+        assert_eq!(n, self.row_len);
+        assert!(i < n);
+        let mut write = 0;
+        for read in 0..n * n {
+            if read / n != i && read % n != i {
+                self.vec[write] = self.vec[read];
+                write += 1;
+            }
+        }
+        self.vec.truncate(write);
+        self.col_len -= 1;
+        self.row_len -= 1;
+    }
+
     #[inline]
     fn at(&self, i: usize, j: usize) -> &T {
         unsafe {
-            return self.vec.get_unchecked(i * self.col_len + j);
+            return self.vec.get_unchecked(i * self.row_len + j);
         }
     }
     #[inline]
     fn at_mut(&mut self, i: usize, j: usize) -> &mut T {
         unsafe {
-            return self.vec.get_unchecked_mut(i * self.col_len + j);
+            return self.vec.get_unchecked_mut(i * self.row_len + j);
         }
     }
 }
@@ -0,0 +1,147 @@
+//! lowest common ancestor queries on any rooted tree by binary lifting, see
+//! `tree::rawptr::BinaryTree::lca_index` and `graph::hash_map::Dominators::lca_index`.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// lowest common ancestor index of a rooted tree, `O(n log n)` to build and
+/// `O(log n)` per query. it doesn't see later changes of the tree.
+#[derive(Debug, Clone)]
+pub struct LcaIndex<N> {
+    /// nodes in breadth first order, the root first.
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    depth: Vec<usize>,
+    /// `up[j][v]` is the `2^j`th ancestor of `v`, the root is its own parent.
+    up: Vec<Vec<usize>>,
+}
+
+impl<N> LcaIndex<N>
+where
+    N: Hash + Eq + Clone,
+{
+    /// tree of `root` and every node reachable from it by `children`. a node
+    /// reachable in more than one way keeps the parent it's first reached from.
+    pub fn new<F, I>(root: N, mut children: F) -> Self
+    where
+        F: FnMut(&N) -> I,
+        I: IntoIterator<Item = N>,
+    {
+        let mut nodes = vec![root.clone()];
+        let mut index = HashMap::from([(root, 0)]);
+        let mut parent = vec![0];
+        let mut depth: Vec<usize> = vec![0];
+        let mut i = 0;
+        while i < nodes.len() {
+            for child in children(&nodes[i]) {
+                if !index.contains_key(&child) {
+                    index.insert(child.clone(), nodes.len());
+                    nodes.push(child);
+                    parent.push(i);
+                    depth.push(depth[i] + 1);
+                }
+            }
+            i += 1;
+        }
+
+        let max_depth = depth.last().copied().unwrap_or(0);
+        let levels = (usize::BITS - max_depth.leading_zeros()).max(1) as usize;
+        let mut up = vec![parent];
+        for j in 1..levels {
+            let next = up[j - 1].iter().map(|&p| up[j - 1][p]).collect();
+            up.push(next);
+        }
+        Self {
+            nodes,
+            index,
+            depth,
+            up,
+        }
+    }
+
+    pub fn root(&self) -> &N {
+        &self.nodes[0]
+    }
+
+    pub fn nodes_len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.index.contains_key(node)
+    }
+
+    /// # Returns
+    /// number of edges from the root to `node`, None if tree doesn't contain `node`.
+    pub fn depth(&self, node: &N) -> Option<usize> {
+        self.index.get(node).map(|&v| self.depth[v])
+    }
+
+    /// # Returns
+    /// `k`th ancestor of `node`, `node` itself if k == 0.
+    /// None if tree doesn't contain `node` or `k` is more than its depth.
+    pub fn ancestor(&self, node: &N, k: usize) -> Option<&N> {
+        let &v = self.index.get(node)?;
+        match k <= self.depth[v] {
+            true => Some(&self.nodes[self.lift(v, k)]),
+            false => None,
+        }
+    }
+
+    /// # Returns
+    /// deepest node that is an ancestor of both `a` and `b`, a node is an ancestor
+    /// of itself. None if tree doesn't contain `a` or `b`.
+    pub fn lca(&self, a: &N, b: &N) -> Option<&N> {
+        let (&a, &b) = (self.index.get(a)?, self.index.get(b)?);
+        Some(&self.nodes[self.lca_index(a, b)])
+    }
+
+    /// # Returns
+    /// number of edges of the path between `a` and `b`, None if tree doesn't
+    /// contain `a` or `b`.
+    pub fn distance(&self, a: &N, b: &N) -> Option<usize> {
+        let (&a, &b) = (self.index.get(a)?, self.index.get(b)?);
+        let lca = self.lca_index(a, b);
+        Some(self.depth[a] + self.depth[b] - 2 * self.depth[lca])
+    }
+
+    /// # Returns
+    /// true if `ancestor` is on the path from the root to `node`, both included.
+    /// false if tree doesn't contain `ancestor` or `node`.
+    pub fn is_ancestor(&self, ancestor: &N, node: &N) -> bool {
+        match (self.index.get(ancestor), self.index.get(node)) {
+            (Some(&a), Some(&v)) => {
+                self.depth[a] <= self.depth[v] && self.lift(v, self.depth[v] - self.depth[a]) == a
+            }
+            _ => false,
+        }
+    }
+
+    /// `k`th ancestor of `v`, k must not be more than its depth.
+    fn lift(&self, mut v: usize, k: usize) -> usize {
+        for (j, up) in self.up.iter().enumerate() {
+            if k >> j & 1 == 1 {
+                v = up[v];
+            }
+        }
+        v
+    }
+
+    fn lca_index(&self, a: usize, b: usize) -> usize {
+        let (mut a, mut b) = match self.depth[a] >= self.depth[b] {
+            true => (a, b),
+            false => (b, a),
+        };
+        a = self.lift(a, self.depth[a] - self.depth[b]);
+        if a == b {
+            return a;
+        }
+        // the highest ancestors that differ are children of the lca
+        for up in self.up.iter().rev() {
+            if up[a] != up[b] {
+                a = up[a];
+                b = up[b];
+            }
+        }
+        self.up[0][a]
+    }
+}
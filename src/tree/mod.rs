@@ -1,2 +1,3 @@
 pub mod cell;
+pub mod lca;
 pub mod rawptr;
@@ -1,4 +1,6 @@
-use std::collections::VecDeque;
+use std::{collections::VecDeque, marker::PhantomData};
+
+use super::lca;
 
 #[derive(Debug)]
 pub struct BinaryTree<T> {
@@ -129,6 +131,64 @@ impl<T> BinaryTree<T> {
             self.root = None;
         }
     }
+
+    /// lowest common ancestor index of the tree, nodes are told apart by address.
+    ///
+    /// # Returns
+    /// None if the tree is empty.
+    pub fn lca_index(&self) -> Option<LcaIndex<'_, T>> {
+        let root = self.root? as *const Node<T>;
+        let index = lca::LcaIndex::new(root, |&n| unsafe {
+            [(*n).left, (*n).right]
+                .into_iter()
+                .flatten()
+                .map(|p| p as *const Node<T>)
+        });
+        Some(LcaIndex {
+            index,
+            tree: PhantomData,
+        })
+    }
+}
+
+/// lowest common ancestor index of a `BinaryTree`, it borrows the tree so the
+/// tree can't change while it's used.
+#[derive(Debug)]
+pub struct LcaIndex<'a, T> {
+    index: lca::LcaIndex<*const Node<T>>,
+    tree: PhantomData<&'a BinaryTree<T>>,
+}
+
+impl<'a, T> LcaIndex<'a, T> {
+    /// # Returns
+    /// deepest node that is an ancestor of both `a` and `b`, a node is an ancestor
+    /// of itself. None if `a` or `b` isn't a node of the tree.
+    pub fn lca(&self, a: &Node<T>, b: &Node<T>) -> Option<&'a Node<T>> {
+        self.index
+            .lca(&(a as *const Node<T>), &(b as *const Node<T>))
+            .map(|&n| unsafe { &*n })
+    }
+
+    /// # Returns
+    /// number of edges from the root to `node`, None if `node` isn't a node of the tree.
+    pub fn depth(&self, node: &Node<T>) -> Option<usize> {
+        self.index.depth(&(node as *const Node<T>))
+    }
+
+    /// # Returns
+    /// number of edges of the path between `a` and `b`, None if `a` or `b` isn't a
+    /// node of the tree.
+    pub fn distance(&self, a: &Node<T>, b: &Node<T>) -> Option<usize> {
+        self.index
+            .distance(&(a as *const Node<T>), &(b as *const Node<T>))
+    }
+
+    /// # Returns
+    /// true if `ancestor` is on the path from the root to `node`, both included.
+    pub fn is_ancestor(&self, ancestor: &Node<T>, node: &Node<T>) -> bool {
+        self.index
+            .is_ancestor(&(ancestor as *const Node<T>), &(node as *const Node<T>))
+    }
 }
 
 impl<T: PartialEq> PartialEq for Node<T> {
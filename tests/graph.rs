@@ -1,7 +1,20 @@
 #![cfg(test)]
 
-use ds_rs::graph::hash_map::{Edge, EdgeErr, Graph, Node};
+use ds_rs::graph::hash_map::{self, multi, undirected, Edge, EdgeErr, Graph, IntegrityErr, Node};
+use ds_rs::graph::io::{self, ParseErr, ParseErrKind};
+use ds_rs::graph::traits::{EdgeWeights, IntoNeighbors, NodeCount};
+use ds_rs::graph::{
+    algo, arena, assignment, clique,
+    coloring::{self, Strategy},
+    csr::CsrGraph,
+    generators, matrix,
+    union_find::DisjointSet,
+    visit::{self, Control, DfsEvent},
+};
 use pretty_assertions::assert_eq;
+use rand::{rngs::StdRng, SeedableRng};
+use std::collections::{hash_map::RandomState, HashMap};
+use std::hash::{BuildHasherDefault, DefaultHasher};
 
 #[test]
 fn test_basic() {
@@ -298,3 +311,2207 @@ fn circular_slice_assert_eq<T: Clone + PartialEq + std::fmt::Debug>(x: &[T], y:
         );
     }
 }
+
+fn scc_graph() -> Graph<char, (), usize> {
+    let mut g = Graph::new();
+    for c in ['a', 'b', 'c', 'd', 'e', 'f', 'g'] {
+        g.insert_node(c, ());
+    }
+    g.insert_edge('a', 'b', 1).unwrap();
+    g.insert_edge('b', 'c', 1).unwrap();
+    g.insert_edge('c', 'a', 1).unwrap();
+    g.insert_edge('c', 'd', 4).unwrap();
+    g.insert_edge('b', 'd', 2).unwrap();
+    g.insert_edge('d', 'e', 1).unwrap();
+    g.insert_edge('e', 'd', 1).unwrap();
+    g.insert_edge('e', 'f', 1).unwrap();
+    g
+}
+
+fn sorted_components(components: Vec<Vec<&char>>) -> Vec<Vec<char>> {
+    let mut ret: Vec<Vec<char>> = components
+        .into_iter()
+        .map(|c| {
+            let mut c: Vec<char> = c.into_iter().copied().collect();
+            c.sort();
+            c
+        })
+        .collect();
+    ret.sort();
+    ret
+}
+
+#[test]
+fn test_scc() {
+    let g = scc_graph();
+    let expected = vec![vec!['a', 'b', 'c'], vec!['d', 'e'], vec!['f'], vec!['g']];
+    assert_eq!(sorted_components(g.tarjan_scc()), expected);
+    assert_eq!(sorted_components(g.kosaraju_scc()), expected);
+
+    let tarjan = g.tarjan_scc();
+    let pos = |k: char| tarjan.iter().position(|c| c.contains(&&k)).unwrap();
+    assert!(pos('a') > pos('d'));
+    assert!(pos('d') > pos('f'));
+
+    let kosaraju = g.kosaraju_scc();
+    let pos = |k: char| kosaraju.iter().position(|c| c.contains(&&k)).unwrap();
+    assert!(pos('a') < pos('d'));
+    assert!(pos('d') < pos('f'));
+
+    let empty: Graph<char, (), usize> = Graph::new();
+    assert!(empty.tarjan_scc().is_empty());
+    assert!(empty.kosaraju_scc().is_empty());
+}
+
+#[test]
+fn test_dfs_times() {
+    let g = scc_graph();
+    let times = g.dfs_times();
+    assert_eq!(times.len(), 7);
+    for (_, (discovery, finish)) in times.iter() {
+        assert!(discovery < finish);
+    }
+    for edge in g.edges() {
+        let (from_disc, from_fin) = times[edge.from];
+        let (to_disc, to_fin) = times[edge.to];
+        let back_edge = to_disc <= from_disc && from_fin <= to_fin;
+        assert!(back_edge || to_fin < from_fin);
+    }
+}
+
+#[test]
+fn test_condensation() {
+    let g = scc_graph();
+    let dag = g.condensation();
+    assert_eq!(dag.nodes_len(), 4);
+    assert_eq!(dag.edges_len(), 2);
+
+    let find = |k: char| {
+        dag.iter()
+            .find(|(_, n)| n.val().contains(&k))
+            .map(|(i, _)| *i)
+            .unwrap()
+    };
+    let (abc, de, f) = (find('a'), find('d'), find('f'));
+    assert!(abc < de && de < f);
+    assert_eq!(dag.get_weight(abc, de), Ok(&2));
+    assert_eq!(dag.get_weight(de, f), Ok(&1));
+    assert!(dag.tarjan_scc().iter().all(|c| c.len() == 1));
+}
+
+#[test]
+fn test_disjoint_set() {
+    let mut set = DisjointSet::new(6);
+    assert_eq!(set.len(), 6);
+    assert_eq!(set.sets_len(), 6);
+
+    assert!(set.union(0, 1));
+    assert!(set.union(2, 3));
+    assert!(set.union(1, 3));
+    assert!(!set.union(0, 2));
+    assert_eq!(set.sets_len(), 3);
+    assert!(set.is_same_set(0, 3));
+    assert!(!set.is_same_set(0, 4));
+    assert_eq!(set.find(2), set.find(1));
+
+    let x = set.push();
+    assert_eq!(x, 6);
+    assert_eq!(set.sets_len(), 4);
+    assert!(set.union(x, 5));
+    assert_eq!(set.sets(), vec![vec![0, 1, 2, 3], vec![4], vec![5, 6]]);
+}
+
+fn mst_graph() -> Graph<char, (), usize> {
+    let mut g = Graph::new();
+    for c in ['a', 'b', 'c', 'd', 'e', 'x', 'y'] {
+        g.insert_node(c, ());
+    }
+    g.insert_edge('a', 'b', 4).unwrap();
+    g.insert_edge('a', 'c', 1).unwrap();
+    g.insert_edge('c', 'b', 2).unwrap();
+    g.insert_edge('b', 'd', 5).unwrap();
+    g.insert_edge('d', 'c', 8).unwrap();
+    g.insert_edge('d', 'e', 3).unwrap();
+    g.insert_edge('e', 'c', 9).unwrap();
+    g.insert_edge('x', 'y', 7).unwrap();
+    g.insert_edge('y', 'x', 6).unwrap();
+    g
+}
+
+#[test]
+fn test_minimum_spanning_forest() {
+    let g = mst_graph();
+    for (edges, total) in [
+        g.minimum_spanning_forest(),
+        g.minimum_spanning_forest_prim(),
+    ] {
+        assert_eq!(total, 1 + 2 + 5 + 3 + 6);
+        assert_eq!(edges.len(), 5);
+        assert!(edges.contains(&Edge::new(&'a', &'c', &1)));
+        assert!(edges.contains(&Edge::new(&'c', &'b', &2)));
+        assert!(edges.contains(&Edge::new(&'b', &'d', &5)));
+        assert!(edges.contains(&Edge::new(&'d', &'e', &3)));
+        assert!(edges.contains(&Edge::new(&'y', &'x', &6)));
+    }
+
+    let empty: Graph<char, (), usize> = Graph::new();
+    assert_eq!(empty.minimum_spanning_forest(), (vec![], 0));
+    assert_eq!(empty.minimum_spanning_forest_prim(), (vec![], 0));
+}
+
+#[test]
+fn test_matrix_minimum_spanning_forest() {
+    let mut g: matrix::undirected::Graph<6, u32> = matrix::undirected::Graph::new();
+    g.insert_edge_unwrap(0, 1, 4);
+    g.insert_edge_unwrap(0, 2, 1);
+    g.insert_edge_unwrap(2, 1, 2);
+    g.insert_edge_unwrap(1, 3, 5);
+    g.insert_edge_unwrap(3, 2, 8);
+    g.insert_edge_unwrap(4, 5, 3);
+
+    let (edges, total) = g.minimum_spanning_forest();
+    assert_eq!(total, 11);
+    assert_eq!(
+        edges,
+        vec![
+            matrix::Edge::new(0, 2, 1),
+            matrix::Edge::new(1, 2, 2),
+            matrix::Edge::new(4, 5, 3),
+            matrix::Edge::new(1, 3, 5),
+        ]
+    );
+
+    let (edges, total) = g.minimum_spanning_forest_prim();
+    assert_eq!(total, 11);
+    assert_eq!(edges.len(), 4);
+    assert!(edges.contains(&matrix::Edge::new(2, 1, 2)));
+    assert!(edges.contains(&matrix::Edge::new(4, 5, 3)));
+}
+
+fn flow_graph() -> Graph<char, (), u32> {
+    let mut g = Graph::new();
+    for c in ['s', 'a', 'b', 'c', 'd', 't'] {
+        g.insert_node(c, ());
+    }
+    g.insert_edge('s', 'a', 16).unwrap();
+    g.insert_edge('s', 'c', 13).unwrap();
+    g.insert_edge('a', 'b', 12).unwrap();
+    g.insert_edge('c', 'a', 4).unwrap();
+    g.insert_edge('b', 'c', 9).unwrap();
+    g.insert_edge('c', 'd', 14).unwrap();
+    g.insert_edge('d', 'b', 7).unwrap();
+    g.insert_edge('b', 't', 20).unwrap();
+    g.insert_edge('d', 't', 4).unwrap();
+    g
+}
+
+#[test]
+fn test_max_flow() {
+    let g = flow_graph();
+    for flow in [
+        g.max_flow(&'s', &'t').unwrap(),
+        g.max_flow_edmonds_karp(&'s', &'t').unwrap(),
+    ] {
+        assert_eq!(flow.value, 23);
+        assert_eq!(flow.flows.len(), g.edges_len());
+        for edge in g.edges() {
+            assert!(flow.flows[&(edge.from, edge.to)] <= *edge.weight);
+        }
+        let out_of_s: u32 = ['a', 'c'].iter().map(|k| flow.flows[&(&'s', k)]).sum();
+        assert_eq!(out_of_s, 23);
+
+        let mut source_side: Vec<char> = flow.source_side.iter().map(|k| **k).collect();
+        source_side.sort();
+        assert_eq!(source_side, vec!['a', 'c', 'd', 's']);
+        let cut: u32 = g
+            .edges()
+            .iter()
+            .filter(|e| flow.source_side.contains(e.from) && !flow.source_side.contains(e.to))
+            .map(|e| *e.weight)
+            .sum();
+        assert_eq!(cut, 23);
+    }
+
+    assert_eq!(g.max_flow(&'s', &'s').unwrap().value, 0);
+    assert_eq!(g.max_flow(&'t', &'s').unwrap().value, 0);
+    assert_eq!(g.max_flow(&'x', &'t').unwrap_err(), EdgeErr::FromNone);
+    assert_eq!(g.max_flow(&'s', &'x').unwrap_err(), EdgeErr::ToNone);
+}
+
+#[test]
+fn test_min_cost_max_flow() {
+    let mut g: Graph<u8, (), (i64, i64)> = Graph::new();
+    for i in 0..4 {
+        g.insert_node(i, ());
+    }
+    g.insert_edge(0, 1, (2, 1)).unwrap();
+    g.insert_edge(0, 2, (1, 2)).unwrap();
+    g.insert_edge(1, 2, (1, 1)).unwrap();
+    g.insert_edge(1, 3, (1, 3)).unwrap();
+    g.insert_edge(2, 3, (2, 1)).unwrap();
+
+    let flow = g.min_cost_max_flow(&0, &3).unwrap();
+    assert_eq!(flow.value, 3);
+    assert_eq!(flow.cost, 3 + 3 + 4);
+    assert_eq!(flow.flows[&(&0, &1)], 2);
+    assert_eq!(flow.flows[&(&1, &2)], 1);
+    assert_eq!(flow.flows[&(&2, &3)], 2);
+    assert_eq!(flow.flows[&(&1, &3)], 1);
+
+    // the second path cancels the flow of 1 -> 2 through its reverse arc
+    let mut g: Graph<u8, (), (u32, u32)> = Graph::new();
+    for i in 0..4 {
+        g.insert_node(i, ());
+    }
+    g.insert_edge(0, 1, (1, 1)).unwrap();
+    g.insert_edge(1, 2, (1, 1)).unwrap();
+    g.insert_edge(2, 3, (1, 1)).unwrap();
+    g.insert_edge(0, 2, (1, 5)).unwrap();
+    g.insert_edge(1, 3, (1, 5)).unwrap();
+    let flow = g.min_cost_max_flow(&0, &3).unwrap();
+    assert_eq!(flow.value, 2);
+    assert_eq!(flow.cost, 1 + 5 + 5 + 1);
+    assert_eq!(flow.flows[&(&1, &2)], 0);
+    assert_eq!(flow.flows[&(&0, &2)], 1);
+    assert_eq!(flow.flows[&(&1, &3)], 1);
+}
+
+#[test]
+fn test_undirected() {
+    let mut g: undirected::Graph<char, (), usize> = undirected::Graph::new();
+    for c in ['a', 'b', 'c', 'd'] {
+        assert_eq!(g.insert_node(c, ()), None);
+    }
+    assert_eq!(g.insert_node('a', ()), Some(()));
+    assert_eq!(g.insert_edge('a', 'x', 1), Err(EdgeErr::ToNone));
+    assert_eq!(g.insert_edge('x', 'a', 1), Err(EdgeErr::FromNone));
+
+    g.insert_edge('a', 'b', 1).unwrap();
+    g.insert_edge('c', 'b', 2).unwrap();
+    g.insert_edge('c', 'd', 3).unwrap();
+    g.insert_edge('d', 'd', 4).unwrap();
+    assert_eq!(g.edges_len(), 4);
+    assert_eq!(g.edges().len(), 4);
+    assert_eq!(g.as_directed().edges_len(), 7);
+
+    assert_eq!(g.get_weight('b', 'c'), Ok(&2));
+    assert_eq!(g.get_weight('c', 'b'), Ok(&2));
+    assert_eq!(g.set_weight('b', 'c', 5), Ok(2));
+    assert_eq!(g.get_weight('c', 'b'), Ok(&5));
+    assert_eq!(g.set_weight('a', 'd', 5), Err(EdgeErr::ToNone));
+
+    assert_eq!(g.degree(&'b'), Some(2));
+    assert_eq!(g.degree(&'d'), Some(3));
+    assert_eq!(g.degree(&'x'), None);
+
+    assert_eq!(g.remove_edge('b', 'a'), Ok(Some(1)));
+    assert_eq!(g.get_weight('a', 'b'), Err(EdgeErr::ToNone));
+    assert_eq!(g.edges_len(), 3);
+
+    assert!(g.remove('c').is_some());
+    assert_eq!(g.edges_len(), 1);
+    assert!(g.get(&'b').unwrap().neighbors().is_empty());
+    assert_eq!(g.bfs_iter(&'d').count(), 1);
+}
+
+#[test]
+fn test_undirected_from_directed() {
+    let g = undirected::Graph::from(mst_graph());
+    assert_eq!(g.edges_len(), 8);
+    for edge in g.as_directed().edges() {
+        assert_eq!(g.get_weight(*edge.to, *edge.from), Ok(edge.weight));
+    }
+    // 'x' -> 'y' is kept over 'y' -> 'x' since 'x' < 'y'
+    assert_eq!(g.get_weight('x', 'y'), Ok(&7));
+    assert_eq!(g.get_weight('y', 'x'), Ok(&7));
+    let mut directed: Graph<char, (), usize> = Graph::new();
+    for c in ['a', 'b', 'c'] {
+        directed.insert_node(c, ());
+    }
+    directed.insert_edge('c', 'a', 1).unwrap();
+    directed.insert_edge('a', 'c', 2).unwrap();
+    directed.insert_edge('c', 'b', 3).unwrap();
+    let u = undirected::Graph::from(directed);
+    assert_eq!(u.get_weight('c', 'a'), Ok(&2));
+    assert_eq!(u.get_weight('b', 'c'), Ok(&3));
+    assert_eq!(u.edges_len(), 2);
+    assert_eq!(format!("{}", u).matches("from").count(), 2);
+
+    let (edges, total) = g.minimum_spanning_forest();
+    assert_eq!(edges.len(), 5);
+    assert_eq!(total, 1 + 2 + 5 + 3 + 7);
+    assert_eq!(g.minimum_spanning_forest_prim().1, total);
+}
+
+#[test]
+fn test_remove_incoming_edges() {
+    for mut g in [Graph::new(), Graph::with_reverse_index()] {
+        for i in 0..4 {
+            g.insert_node(i, ());
+        }
+        g.insert_edge(0, 1, 1).unwrap();
+        g.insert_edge(2, 1, 1).unwrap();
+        g.insert_edge(1, 3, 1).unwrap();
+        g.insert_edge(3, 3, 1).unwrap();
+        g.insert_edge(3, 0, 1).unwrap();
+
+        let mut ins = g.in_neighbors(&1).unwrap();
+        ins.sort();
+        assert_eq!(ins, vec![&0, &2]);
+        assert_eq!(g.in_degree(&3), Some(2));
+        assert_eq!(g.in_degree(&4), None);
+
+        assert!(g.remove(1).is_some());
+        assert_eq!(g.edges_len(), 2);
+        assert!(g.validate().is_ok());
+        assert_eq!(g.in_degree(&3), Some(1));
+        assert_eq!(g.dijkstra_shortest_dist(&0).len(), 3);
+
+        assert_eq!(g.remove_edge(3, 0), Ok(Some(1)));
+        assert_eq!(g.in_degree(&0), Some(0));
+        assert_eq!(g.remove_edge(3, 3), Ok(Some(1)));
+        assert_eq!(g.in_neighbors(&3), Some(vec![]));
+        assert!(g.validate().is_ok());
+    }
+}
+
+#[test]
+fn test_validate() {
+    let mut g: Graph<i32, (), i32> = Graph::new();
+    g.insert_node(0, ());
+    g.insert_node(1, ());
+    g.insert_edge(0, 1, 1).unwrap();
+
+    let mut other: Graph<i32, (), i32> = Graph::new();
+    other.insert_node(2, ());
+    other.insert_node(5, ());
+    other.insert_edge(2, 5, 1).unwrap();
+    g.insert(other.get(&2).unwrap().clone());
+    assert_eq!(
+        g.validate(),
+        Err(vec![IntegrityErr::DanglingEdge { from: &2, to: &5 }])
+    );
+
+    g.enable_reverse_index();
+    assert!(g.has_reverse_index());
+    assert_eq!(g.in_neighbors(&1), Some(vec![&0]));
+    assert_eq!(
+        g.validate(),
+        Err(vec![IntegrityErr::DanglingEdge { from: &2, to: &5 }])
+    );
+    assert!(g.remove(2).is_some());
+    assert!(g.validate().is_ok());
+
+    g.disable_reverse_index();
+    assert!(!g.has_reverse_index());
+    assert_eq!(g.in_degree(&1), Some(1));
+}
+
+#[test]
+fn test_multigraph() {
+    let mut g: multi::Graph<char, (), usize> = multi::Graph::new();
+    for c in ['a', 'b', 'c', 'd'] {
+        g.insert_node(c, ());
+    }
+    let ab_slow = g.insert_edge('a', 'b', 10).unwrap();
+    let ab_fast = g.insert_edge('a', 'b', 2).unwrap();
+    let bc = g.insert_edge('b', 'c', 3).unwrap();
+    let ac = g.insert_edge('a', 'c', 6).unwrap();
+    let cd = g.insert_edge('c', 'd', 1).unwrap();
+    let cd_2 = g.insert_edge('c', 'd', 1).unwrap();
+    assert_eq!(g.insert_edge('a', 'x', 1), Err(EdgeErr::ToNone));
+    assert_eq!(g.edges_len(), 6);
+    assert_ne!(cd, cd_2);
+
+    let between: Vec<_> = g
+        .edges_between(&'a', &'b')
+        .unwrap()
+        .iter()
+        .map(|e| e.id)
+        .collect();
+    assert_eq!(between, vec![ab_slow, ab_fast]);
+    assert_eq!(g.min_edge(&'a', &'b').unwrap().id, ab_fast);
+    assert_eq!(g.edges_between(&'x', &'b'), Err(EdgeErr::FromNone));
+
+    assert_eq!(
+        g.dijkstra_shortest_path(&'a', &'d'),
+        Some((vec![ab_fast, bc, cd], 6))
+    );
+    let dist = g.dijkstra_shortest_dist(&'a');
+    assert_eq!(dist[&'c'], 5);
+    assert_eq!(dist.get(&'x'), None);
+
+    assert_eq!(g.remove_edge(ab_fast), Some(2));
+    assert_eq!(g.remove_edge(ab_fast), None);
+    assert_eq!(g.dijkstra_shortest_path(&'a', &'c'), Some((vec![ac], 6)));
+    assert_eq!(g.edge(bc).unwrap().from, &'b');
+
+    *g.get_weight_mut(ac).unwrap() = 20;
+    let simple = g.to_simple();
+    assert_eq!(simple.edges_len(), 4);
+    assert_eq!(simple.get_weight('a', 'b'), Ok(&10));
+    assert_eq!(simple.get_weight('a', 'c'), Ok(&20));
+
+    assert_eq!(g.remove('c'), Some(()));
+    assert_eq!(g.edges_len(), 1);
+    assert_eq!(g.edge(cd), None);
+    assert!(g.get(&'d').unwrap().in_neighbors().is_empty());
+    assert!(g.get(&'b').unwrap().neighbors().is_empty());
+}
+
+#[test]
+fn test_arena() {
+    let mut g: arena::Graph<char, usize> = arena::Graph::new();
+    let a = g.add_node('a');
+    let b = g.add_node('b');
+    let c = g.add_node('c');
+    let ab = g.add_edge(a, b, 1).unwrap();
+    let bc = g.add_edge(b, c, 2).unwrap();
+    let ac = g.add_edge(a, c, 5).unwrap();
+    assert_eq!(g.nodes_len(), 3);
+    assert_eq!(g.edges_len(), 3);
+    assert_eq!(g.node(b), Some(&'b'));
+    assert_eq!(g.find_edge(a, c), Some(ac));
+    assert_eq!(g.edge_endpoints(bc), Some((b, c)));
+    assert_eq!(g.neighbors(a).collect::<Vec<_>>(), vec![(b, &1), (c, &5)]);
+    assert_eq!(
+        g.in_neighbors(c).collect::<Vec<_>>(),
+        vec![(b, &2), (a, &5)]
+    );
+    assert_eq!(g.dijkstra_shortest_path(a, c), Some((vec![a, b, c], 3)));
+    assert_eq!(g.bfs_iter(a).collect::<Vec<_>>(), vec![a, b, c]);
+    assert_eq!(g.dfs_iter(a).count(), 3);
+
+    assert_eq!(g.remove_node(b), Some('b'));
+    assert_eq!(g.remove_node(b), None);
+    assert_eq!(g.edges_len(), 1);
+    assert_eq!(g.get_weight(ab), None);
+    assert_eq!(g.dijkstra_shortest_path(a, c), Some((vec![a, c], 5)));
+
+    let d = g.add_node('d');
+    assert_eq!(d.index(), b.index());
+    assert_ne!(d, b);
+    assert_eq!(g.node(b), None);
+    assert_eq!(g.add_edge(b, d, 1), Err(EdgeErr::FromNone));
+    assert_eq!(g.add_edge(d, b, 1), Err(EdgeErr::ToNone));
+    let ad = g.add_edge(a, d, 7).unwrap();
+    assert_eq!(ad.index(), ab.index());
+    assert_eq!(g.get_weight(ab), None);
+    *g.get_weight_mut(ad).unwrap() = 8;
+    assert_eq!(g.remove_edge(ad), Some(8));
+    assert_eq!(g.neighbors(a).count(), 1);
+
+    g.clear();
+    assert!(g.is_empty());
+    assert!(!g.contains_node(a));
+}
+
+#[test]
+fn test_arena_conversion() {
+    let keyed = mst_graph();
+    let g = arena::Graph::from(&keyed);
+    assert_eq!(g.nodes_len(), keyed.nodes_len());
+    assert_eq!(g.edges_len(), keyed.edges_len());
+    let (a, _) = g.iter().find(|(_, (k, _))| *k == 'a').unwrap();
+    let (e, _) = g.iter().find(|(_, (k, _))| *k == 'e').unwrap();
+    assert_eq!(g.dijkstra_shortest_path(a, e).unwrap().1, 1 + 2 + 5 + 3);
+
+    let back: Graph<char, (), usize> = Graph::from(&g);
+    assert_eq!(back.nodes_len(), keyed.nodes_len());
+    for edge in keyed.edges() {
+        assert_eq!(back.get_weight(*edge.from, *edge.to), Ok(edge.weight));
+    }
+}
+
+#[test]
+fn test_csr() {
+    let g = CsrGraph::from_edges(
+        5,
+        vec![
+            (0, 1, 'a'),
+            (0, 2, 'b'),
+            (2, 3, 'c'),
+            (1, 3, 'd'),
+            (3, 0, 'e'),
+        ],
+    );
+    assert_eq!(g.nodes_len(), 5);
+    assert_eq!(g.edges_len(), 5);
+    assert_eq!(g.neighbors(0), &[1, 2]);
+    assert_eq!(g.weights(0), &['a', 'b']);
+    assert_eq!(g.degree(4), 0);
+    assert_eq!(g.transpose().neighbors(3), &[1, 2]);
+
+    assert_eq!(g.bfs_iter(0).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    assert_eq!(g.dfs_iter(0).collect::<Vec<_>>(), vec![0, 2, 3, 1]);
+    let levels = vec![Some(0), Some(1), Some(1), Some(2), None];
+    assert_eq!(g.bfs_levels(0), levels);
+    assert_eq!(g.bfs_levels_par(0), levels);
+}
+
+#[test]
+fn test_csr_from_graph() {
+    let keyed = scc_graph();
+    let (g, keys) = CsrGraph::from_graph(&keyed);
+    assert_eq!(g.nodes_len(), keyed.nodes_len());
+    assert_eq!(g.edges_len(), keyed.edges_len());
+    let a = keys.iter().position(|k| *k == 'a').unwrap();
+    let reached: Vec<char> = g.bfs_iter(a).map(|i| keys[i]).collect();
+    assert_eq!(reached.len(), 6);
+    assert!(!reached.contains(&'g'));
+    assert_eq!(g.bfs_levels_par(a), g.bfs_levels(a));
+}
+
+#[test]
+fn test_csr_pagerank() {
+    let g = CsrGraph::from_edges(4, vec![(0, 1, ()), (1, 2, ()), (2, 0, ()), (3, 0, ())]);
+    let ranks = g.pagerank(0.85, 1e-10, 1000);
+    assert!((ranks.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    assert!((ranks[3] - 0.15 / 4.0).abs() < 1e-9);
+    assert!(ranks[0] > ranks[1] && ranks[1] > ranks[3]);
+    assert_eq!(g.pagerank_par(0.85, 1e-10, 1000), ranks);
+
+    let cycle = CsrGraph::from_edges(3, vec![(0, 1, ()), (1, 2, ()), (2, 0, ())]);
+    for rank in cycle.pagerank(0.85, 1e-12, 100) {
+        assert!((rank - 1.0 / 3.0).abs() < 1e-9);
+    }
+    let dangling = CsrGraph::from_edges(2, vec![(0, 1, ())]);
+    let ranks = dangling.pagerank(0.85, 1e-12, 1000);
+    assert!((ranks.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    assert!(CsrGraph::<()>::from_edges(0, vec![])
+        .pagerank(0.85, 1e-6, 10)
+        .is_empty());
+}
+
+/// number of edges out of every node, written once for every graph type.
+fn out_degrees<G: NodeCount + IntoNeighbors>(g: G, nodes: &[G::NodeId]) -> Vec<usize> {
+    assert_eq!(g.node_count(), nodes.len());
+    nodes.iter().map(|n| g.neighbors(*n).count()).collect()
+}
+
+#[test]
+fn test_graph_traits() {
+    let mut h: Graph<usize, (), u32> = Graph::new();
+    let mut d: matrix::directed::Graph<4, u32> = matrix::directed::Graph::new();
+    for i in 0..4 {
+        h.insert_node(i, ());
+    }
+    for (from, to, weight) in [(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 5)] {
+        h.insert_edge(from, to, weight).unwrap();
+        d.insert_edge(from, to, weight).unwrap();
+    }
+    let mut u: matrix::undirected::Graph<4, u32> = matrix::undirected::Graph::new();
+    u.insert_edge_unwrap(0, 1, 4);
+    u.insert_edge_unwrap(1, 2, 3);
+
+    assert_eq!(out_degrees(&h, &[&0, &1, &2, &3]), vec![2, 1, 1, 0]);
+    assert_eq!(out_degrees(&d, &[0, 1, 2, 3]), vec![2, 1, 1, 0]);
+    assert_eq!(out_degrees(&u, &[0, 1, 2, 3]), vec![1, 2, 1, 0]);
+    assert_eq!((&d).edge_weight(0, 2), Some(&1));
+    assert_eq!((&d).edge_weight(2, 0), None);
+    assert_eq!((&u).edge_weight(2, 1), Some(&3));
+    assert_eq!((&h).edge_weight(&2, &1), Some(&2));
+
+    assert_eq!(
+        algo::dijkstra_shortest_path(&h, &0, &3),
+        Some((vec![&0, &2, &1, &3], 8))
+    );
+    assert_eq!(
+        algo::dijkstra_shortest_path(&d, 0, 3),
+        Some((vec![0, 2, 1, 3], 8))
+    );
+    assert_eq!(algo::dijkstra_shortest_path(&d, 3, 0), None);
+    assert_eq!(algo::dijkstra(&u, 2).get(&0), Some(&7));
+    assert_eq!(algo::dijkstra(&u, 2).get(&3), None);
+
+    assert_eq!(algo::bfs(&d, 0).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    let mut dfs: Vec<usize> = algo::dfs(&u, 1).collect();
+    dfs.sort();
+    assert_eq!(dfs, vec![0, 1, 2]);
+    assert_eq!(algo::bfs(&h, &3).count(), 1);
+
+    // 0 -> 2 -> 1 -> 3 plus 0 -> 1 leaves 0 with two edges out
+    assert!(!algo::has_eulerian_path(&d));
+    d.remove_edge(0, 1).unwrap();
+    assert_eq!(algo::eulerian_path(&d), Some(vec![0, 2, 1, 3]));
+    d.insert_edge(3, 0, 1).unwrap();
+    assert_eq!(algo::eulerian_path(&d).unwrap().len(), 5);
+    // the edge 2 -> 3 can't be reached from the cycle 0 -> 1 -> 0
+    let mut g: Graph<usize, (), u32> = Graph::new();
+    for i in 0..4 {
+        g.insert_node(i, ());
+    }
+    g.insert_edge(0, 1, 1).unwrap();
+    g.insert_edge(1, 0, 1).unwrap();
+    g.insert_edge(2, 3, 1).unwrap();
+    assert!(!g.has_eulerian_path());
+    assert_eq!(g.find_eulerian_path(), None);
+}
+
+#[test]
+fn test_matrix_graph() {
+    let mut d: matrix::directed::Graph<4, i32> = matrix::directed::Graph::default();
+    assert_eq!(d.insert_edge(0, 1, 0), Ok(None));
+    assert_eq!(d.insert_edge(0, 1, -3), Ok(Some(0)));
+    assert_eq!(d.insert_edge(1, 2, 0), Ok(None));
+    assert_eq!(d.insert_edge(3, 3, 7), Ok(None));
+    assert_eq!(d.insert_edge(4, 0, 1), Err(EdgeErr::FromNone));
+    assert_eq!(d.insert_edge(0, 4, 1), Err(EdgeErr::ToNone));
+    // a zero weight is still an edge
+    assert!(d.contains_edge(1, 2));
+    assert_eq!(d.get_edge(1, 2), Some(&0));
+    assert_eq!(d.get_edge(2, 1), None);
+    assert_eq!(d.get_edge(9, 9), None);
+    assert_eq!(d.edges_len(), 3);
+    assert_eq!(d.neighbors(0).collect::<Vec<_>>(), vec![1]);
+    assert_eq!(d.out_degree(3), Some(1));
+    assert_eq!(d.in_degree(2), Some(1));
+    assert_eq!(d.in_degree(4), None);
+    assert_eq!(d.bfs_iter(0).collect::<Vec<_>>(), vec![0, 1, 2]);
+    assert_eq!(d.dfs_iter(3).collect::<Vec<_>>(), vec![3]);
+
+    let h = Graph::from(&d);
+    assert_eq!(h.nodes_len(), 4);
+    assert_eq!(h.get_weight(0, 1), Ok(&-3));
+    let (back, keys) = matrix::directed::Graph::<4, i32>::from_graph(&h).unwrap();
+    assert_eq!(back.edges_len(), 3);
+    for edge in back.edges() {
+        assert_eq!(
+            h.get_weight(keys[edge.from], keys[edge.to]),
+            Ok(&edge.weight)
+        );
+    }
+    assert!(matrix::directed::Graph::<3, i32>::from_graph(&h).is_none());
+
+    assert_eq!(d.remove_edge(0, 1), Ok(Some(-3)));
+    assert_eq!(d.remove_edge(0, 1), Ok(None));
+    assert_eq!(d.in_degree(1), Some(0));
+
+    let mut u: matrix::undirected::Graph<3, u8> = matrix::undirected::Graph::new();
+    assert_eq!(u.insert_edge(0, 1, 0), Ok(None));
+    assert_eq!(u.insert_edge_unwrap(2, 2, 5), None);
+    assert_eq!(u.get_edge(1, 0), Some(&0));
+    assert_eq!(u.degree(1), Some(1));
+    assert_eq!(u.degree(2), Some(2));
+    assert_eq!(u.edges_len(), 2);
+    assert_eq!(
+        u.edges(),
+        vec![matrix::Edge::new(0, 1, 0), matrix::Edge::new(2, 2, 5)]
+    );
+
+    let h = undirected::Graph::from(&u);
+    assert_eq!(h.edges_len(), 2);
+    assert_eq!(h.get_weight(1, 0), Ok(&0));
+    let (back, _) = matrix::undirected::Graph::<3, u8>::from_graph(&h).unwrap();
+    assert_eq!(back.edges_len(), 2);
+
+    assert_eq!(u.remove_edge(1, 0), Ok(Some(0)));
+    assert_eq!(u.get_edge(0, 1), None);
+    assert_eq!(u.bfs_iter(0).collect::<Vec<_>>(), vec![0]);
+}
+
+#[test]
+fn test_dynamic_matrix_graph() {
+    use matrix::dynamic::{BitGraph, Graph};
+
+    let mut g: Graph<u32> = Graph::new();
+    assert!(g.is_empty());
+    for i in 0..4 {
+        assert_eq!(g.add_node(), i);
+    }
+    g.insert_edge(0, 1, 1).unwrap();
+    g.insert_edge(1, 2, 2).unwrap();
+    g.insert_edge(2, 3, 3).unwrap();
+    g.insert_edge(0, 3, 10).unwrap();
+    g.insert_edge(3, 1, 0).unwrap();
+    assert_eq!(g.insert_edge(0, 4, 1), Err(EdgeErr::ToNone));
+    assert_eq!(g.get_edge(0, 4), None);
+    assert_eq!(g.edges_len(), 5);
+    assert_eq!(g.in_degree(1), Some(2));
+    assert_eq!(
+        algo::dijkstra_shortest_path(&g, 0, 3),
+        Some((vec![0, 1, 2, 3], 6))
+    );
+    assert_eq!(g.bfs_iter(0).collect::<Vec<_>>(), vec![0, 1, 3, 2]);
+
+    let bits = BitGraph::from(&g);
+    assert_eq!(bits.edges_len(), 5);
+    assert!(bits.contains_edge(3, 1));
+
+    // node 2 goes away, 3 becomes 2
+    assert!(g.remove_node(2));
+    assert!(!g.remove_node(3));
+    assert_eq!(g.nodes_len(), 3);
+    assert_eq!(
+        g.edges(),
+        vec![
+            matrix::Edge::new(0, 1, 1),
+            matrix::Edge::new(0, 2, 10),
+            matrix::Edge::new(2, 1, 0),
+        ]
+    );
+    assert_eq!(g.add_node(), 3);
+    assert_eq!(g.out_degree(3), Some(0));
+    assert_eq!(g.remove_edge(0, 2), Ok(Some(10)));
+
+    let mut b = BitGraph::with_nodes(100);
+    assert_eq!(b.insert_edge(0, 99), Ok(true));
+    assert_eq!(b.insert_edge(0, 99), Ok(false));
+    b.insert_edge(99, 50).unwrap();
+    b.insert_edge(50, 0).unwrap();
+    assert_eq!(b.insert_edge(100, 0), Err(EdgeErr::FromNone));
+    assert_eq!(b.dfs_iter(0).collect::<Vec<_>>(), vec![0, 99, 50]);
+    assert!(b.remove_node(0));
+    assert_eq!(b.neighbors(98).collect::<Vec<_>>(), vec![49]);
+    assert_eq!(b.in_degree(49), Some(1));
+    assert_eq!(b.edges_len(), 1);
+    assert_eq!(b.add_node(), 99);
+    assert_eq!(b.remove_edge(98, 49), Ok(true));
+    assert_eq!(b.out_degree(98), Some(0));
+}
+
+#[test]
+fn test_bipartite() {
+    // workers 0..3 and jobs 10..13
+    let mut g: Graph<usize, (), ()> = Graph::new();
+    for i in [0, 1, 2, 10, 11, 12] {
+        g.insert_node(i, ());
+    }
+    for (worker, job) in [(0, 10), (0, 11), (1, 10), (2, 10), (12, 2)] {
+        g.insert_edge(worker, job, ()).unwrap();
+    }
+    let side = g.is_bipartite().unwrap();
+    assert_eq!(side.len(), 6);
+    for edge in g.edges() {
+        assert_ne!(side[edge.from], side[edge.to]);
+    }
+
+    let mut matching = g.maximum_bipartite_matching().unwrap();
+    matching.sort();
+    assert_eq!(matching.len(), 3);
+    assert!(matching.contains(&(&0, &11)));
+    assert!(matching.contains(&(&1, &10)));
+    assert!(matching.contains(&(&12, &2)));
+
+    // 0 - 10 - 1 - 11 - 0 is even, 0 - 10 - 2 - 0 isn't
+    g.insert_edge(1, 11, ()).unwrap();
+    assert!(g.is_bipartite().is_ok());
+    g.insert_edge(2, 0, ()).unwrap();
+    let cycle = g.is_bipartite().unwrap_err();
+    assert_eq!(cycle.len() % 2, 1);
+    for i in 0..cycle.len() {
+        let (a, b) = (cycle[i], cycle[(i + 1) % cycle.len()]);
+        assert!(g.get_weight(*a, *b).is_ok() || g.get_weight(*b, *a).is_ok());
+    }
+    assert!(g.maximum_bipartite_matching().is_err());
+
+    g.clear();
+    g.insert_node(0, ());
+    g.insert_edge(0, 0, ()).unwrap();
+    assert_eq!(g.is_bipartite(), Err(vec![&0]));
+}
+
+#[test]
+fn test_hungarian() {
+    let costs = ds_rs::matrix::vec::Matrix::from([[4, 1, 3], [2, 0, 5], [3, 2, 2]]);
+    assert_eq!(assignment::hungarian(&costs), (vec![1, 0, 2], 5));
+
+    // more columns than rows, negative costs
+    let costs = ds_rs::matrix::vec::Matrix::from([[7, -2, 9, 4], [8, -1, 3, 6]]);
+    assert_eq!(assignment::hungarian(&costs), (vec![1, 2], 1));
+
+    let empty: ds_rs::matrix::vec::Matrix<i32> = ds_rs::matrix::vec::Matrix::from_elem(0, 3, 0);
+    assert_eq!(assignment::hungarian(&empty), (vec![], 0));
+
+    // unsigned costs
+    let costs: ds_rs::matrix::vec::Matrix<u32> =
+        ds_rs::matrix::vec::Matrix::from([[4, 1, 3], [2, 0, 5], [3, 2, 2]]);
+    assert_eq!(assignment::hungarian(&costs), (vec![1, 0, 2], 5));
+    let costs: ds_rs::matrix::vec::Matrix<u32> =
+        ds_rs::matrix::vec::Matrix::from([[9, 2, 7, 8], [6, 4, 3, 7], [5, 8, 1, 8], [7, 6, 9, 4]]);
+    assert_eq!(assignment::hungarian(&costs), (vec![1, 0, 2, 3], 13));
+}
+
+#[test]
+fn test_connectivity() {
+    // triangle a b c, bridge c - d, triangle d e f sharing d with the path d - g,
+    // isolated x
+    let mut g: Graph<char, (), ()> = Graph::new();
+    for key in "abcdefgx".chars() {
+        g.insert_node(key, ());
+    }
+    for (from, to) in [
+        ('a', 'b'),
+        ('b', 'c'),
+        ('c', 'a'),
+        ('a', 'c'),
+        ('c', 'd'),
+        ('d', 'e'),
+        ('e', 'f'),
+        ('f', 'd'),
+        ('g', 'd'),
+    ] {
+        g.insert_edge(from, to, ()).unwrap();
+    }
+
+    let mut components: Vec<Vec<char>> = g
+        .connected_components()
+        .into_iter()
+        .map(|c| {
+            let mut c: Vec<char> = c.into_iter().copied().collect();
+            c.sort();
+            c
+        })
+        .collect();
+    components.sort();
+    assert_eq!(
+        components,
+        vec![vec!['a', 'b', 'c', 'd', 'e', 'f', 'g'], vec!['x']]
+    );
+    assert!(!g.is_connected());
+
+    let mut bridges = g.bridges();
+    bridges.sort();
+    assert_eq!(bridges, vec![(&'c', &'d'), (&'g', &'d')]);
+
+    let mut points = g.articulation_points();
+    points.sort();
+    assert_eq!(points, vec![&'c', &'d']);
+
+    let mut components: Vec<Vec<char>> = g
+        .biconnected_components()
+        .into_iter()
+        .map(|c| {
+            let mut c: Vec<char> = c.into_iter().copied().collect();
+            c.sort();
+            c
+        })
+        .collect();
+    components.sort();
+    assert_eq!(
+        components,
+        vec![
+            vec!['a', 'b', 'c'],
+            vec!['c', 'd'],
+            vec!['d', 'e', 'f'],
+            vec!['d', 'g'],
+        ]
+    );
+
+    g.remove('x');
+    assert!(g.is_connected());
+    g.insert_edge('g', 'a', ()).unwrap();
+    assert!(g.bridges().is_empty());
+    assert_eq!(g.articulation_points(), vec![&'d']);
+}
+
+/// checks that `path` walks every edge of the undirected graph exactly once.
+fn assert_undirected_trail(g: &undirected::Graph<usize, (), ()>, path: &[&usize]) {
+    assert_eq!(path.len(), g.edges_len() + 1);
+    let mut walked = std::collections::HashSet::new();
+    for pair in path.windows(2) {
+        let (x, y) = (*pair[0].min(pair[1]), *pair[0].max(pair[1]));
+        assert!(g.get_weight(x, y).is_ok());
+        assert!(walked.insert((x, y)));
+    }
+}
+
+#[test]
+fn test_eulerian_circuit_and_undirected() {
+    let mut g: Graph<char, (), ()> = Graph::new();
+    for key in "abc".chars() {
+        g.insert_node(key, ());
+    }
+    g.insert_edge('a', 'b', ()).unwrap();
+    g.insert_edge('b', 'c', ()).unwrap();
+    assert!(g.has_eulerian_path());
+    assert!(!g.has_eulerian_circuit());
+    g.insert_edge('c', 'a', ()).unwrap();
+    let mut circuit = g.find_eulerian_circuit().unwrap();
+    assert_eq!(circuit.first(), circuit.last());
+    circuit.pop();
+    circular_slice_assert_eq(&circuit, &[&'a', &'b', &'c']);
+
+    // a long chain would overflow the stack of a recursive search
+    let mut chain: Graph<usize, (), ()> = Graph::new();
+    for i in 0..100_000 {
+        chain.insert_node(i, ());
+    }
+    for i in 1..100_000 {
+        chain.insert_edge(i - 1, i, ()).unwrap();
+    }
+    assert_eq!(chain.find_eulerian_path().unwrap().len(), 100_000);
+
+    // a square with one diagonal has two odd nodes
+    let mut u: undirected::Graph<usize, (), ()> = undirected::Graph::new();
+    for i in 0..4 {
+        u.insert_node(i, ());
+    }
+    for (x, y) in [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)] {
+        u.insert_edge(x, y, ()).unwrap();
+    }
+    let path = u.find_eulerian_path().unwrap();
+    assert_undirected_trail(&u, &path);
+    assert!([&0, &2].contains(&path[0]));
+    assert!(!u.has_eulerian_circuit());
+    u.remove_edge(0, 2).unwrap();
+    let circuit = u.find_eulerian_circuit().unwrap();
+    assert_undirected_trail(&u, &circuit);
+    assert_eq!(circuit.first(), circuit.last());
+    // the directed view walks both directions of every edge
+    assert_eq!(u.as_directed().find_eulerian_circuit().unwrap().len(), 9);
+
+    let mut m: matrix::undirected::Graph<3, u8> = matrix::undirected::Graph::new();
+    m.insert_edge_unwrap(0, 1, 1);
+    m.insert_edge_unwrap(1, 1, 1);
+    assert_eq!(algo::eulerian_path_undirected(&m).unwrap().len(), 3);
+}
+
+#[test]
+fn test_multigraph_eulerian() {
+    let mut g: multi::Graph<char, (), u32> = multi::Graph::new();
+    g.insert_node('a', ());
+    g.insert_node('b', ());
+    let ab1 = g.insert_edge('a', 'b', 1).unwrap();
+    let ba = g.insert_edge('b', 'a', 2).unwrap();
+    let ab2 = g.insert_edge('a', 'b', 3).unwrap();
+    assert_eq!(g.find_eulerian_circuit(), None);
+    let path = g.find_eulerian_path().unwrap();
+    assert_eq!(path.len(), 3);
+    assert_eq!(path[1], ba);
+    assert!(path.contains(&ab1) && path.contains(&ab2));
+
+    g.insert_edge('b', 'a', 4).unwrap();
+    assert_eq!(g.find_eulerian_circuit().unwrap().len(), 4);
+    g.clear();
+    assert_eq!(g.find_eulerian_path(), None);
+}
+
+#[test]
+fn test_hamiltonian() {
+    let mut g: Graph<usize, (), ()> = Graph::new();
+    for i in 0..5 {
+        g.insert_node(i, ());
+    }
+    for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 4), (1, 3), (4, 1)] {
+        g.insert_edge(from, to, ()).unwrap();
+    }
+    assert_eq!(g.find_hamiltonian_path(), Some(vec![&0, &1, &2, &3, &4]));
+    assert_eq!(g.find_hamiltonian_cycle(), None);
+    g.insert_edge(4, 0, ()).unwrap();
+    let cycle = g.find_hamiltonian_cycle().unwrap();
+    circular_slice_assert_eq(&cycle, &[&0, &1, &2, &3, &4]);
+
+    // a star has no Hamiltonian path
+    let mut u: undirected::Graph<usize, (), ()> = undirected::Graph::new();
+    for i in 0..4 {
+        u.insert_node(i, ());
+    }
+    for i in 1..4 {
+        u.insert_edge(0, i, ()).unwrap();
+    }
+    assert_eq!(u.find_hamiltonian_path(), None);
+    u.insert_edge(1, 2, ()).unwrap();
+    let path = u.find_hamiltonian_path().unwrap();
+    assert_eq!(path.len(), 4);
+    assert!(path[0] == &3 || path[3] == &3);
+    assert_eq!(u.find_hamiltonian_cycle(), None);
+
+    // one edge isn't a cycle, unlike edges both ways in a directed graph
+    let mut pair: undirected::Graph<usize, (), ()> = undirected::Graph::new();
+    pair.insert_node(0, ());
+    pair.insert_node(1, ());
+    pair.insert_edge(0, 1, ()).unwrap();
+    assert_eq!(pair.find_hamiltonian_path().unwrap().len(), 2);
+    assert_eq!(pair.find_hamiltonian_cycle(), None);
+    assert_eq!(
+        algo::hamiltonian_cycle(pair.as_directed()).unwrap().len(),
+        2
+    );
+    pair.insert_node(2, ());
+    pair.insert_edge(1, 2, ()).unwrap();
+    pair.insert_edge(2, 0, ()).unwrap();
+    assert_eq!(pair.find_hamiltonian_cycle().unwrap().len(), 3);
+}
+
+/// nodes with their values and edges with their weights, sorted.
+type IoContents<T> = (Vec<(String, T)>, Vec<(String, String, u32)>);
+
+fn io_contents<T: Clone + Ord>(g: &Graph<String, T, u32>) -> IoContents<T> {
+    let mut nodes: Vec<(String, T)> = g
+        .iter()
+        .map(|(k, n)| (k.clone(), n.val().clone()))
+        .collect();
+    let mut edges: Vec<(String, String, u32)> = g
+        .edges()
+        .iter()
+        .map(|e| (e.from.clone(), e.to.clone(), *e.weight))
+        .collect();
+    nodes.sort();
+    edges.sort();
+    (nodes, edges)
+}
+
+#[test]
+fn test_io_round_trip() {
+    let mut g: Graph<String, String, u32> = Graph::new();
+    g.insert_node("a".to_string(), "x y".to_string());
+    g.insert_node("b,c".to_string(), "\"q\" & <r>".to_string());
+    g.insert_node("d".to_string(), "line\nbreak".to_string());
+    g.insert_node(" e".to_string(), "pad".to_string());
+    g.insert_node("f\t".to_string(), "pad".to_string());
+    g.insert_edge("a".to_string(), "b,c".to_string(), 3)
+        .unwrap();
+    g.insert_edge(" e".to_string(), "a".to_string(), 2).unwrap();
+    g.insert_edge("b,c".to_string(), "a".to_string(), 4)
+        .unwrap();
+    g.insert_edge("a".to_string(), "a".to_string(), 1).unwrap();
+
+    let mut dot = String::new();
+    io::write_dot(&g, &mut dot).unwrap();
+    let read: Graph<String, String, u32> = io::read_dot(&dot).unwrap();
+    assert_eq!(io_contents(&read), io_contents(&g));
+
+    let mut graphml = String::new();
+    io::write_graphml(&g, &mut graphml).unwrap();
+    let read: Graph<String, String, u32> = io::read_graphml(&graphml).unwrap();
+    assert_eq!(io_contents(&read), io_contents(&g));
+
+    // node values aren't part of an edge list
+    let mut csv = String::new();
+    io::write_edge_list(&g, &mut csv).unwrap();
+    let read: Graph<String, (), u32> = io::read_edge_list(&csv).unwrap();
+    let mut keys: Vec<&String> = read.iter().map(|(k, _)| k).collect();
+    keys.sort();
+    assert_eq!(keys, vec![" e", "a", "b,c", "d", "f\t"]);
+    assert_eq!(read.edges_len(), 4);
+    assert_eq!(read.get_weight(" e".to_string(), "a".to_string()), Ok(&2));
+    assert_eq!(read.get_weight("b,c".to_string(), "a".to_string()), Ok(&4));
+
+    let mut adj: Graph<usize, (), ()> = Graph::new();
+    for i in 0..4 {
+        adj.insert_node(i, ());
+    }
+    adj.insert_edge(0, 1, ()).unwrap();
+    adj.insert_edge(0, 2, ()).unwrap();
+    adj.insert_edge(2, 0, ()).unwrap();
+    let mut list = String::new();
+    io::write_adjacency_list(&adj, &mut list).unwrap();
+    let read: Graph<usize, (), ()> = io::read_adjacency_list(&list).unwrap();
+    assert_eq!(read.nodes_len(), 4);
+    assert_eq!(read.edges_len(), 3);
+    assert!(read.get_weight(2, 0).is_ok());
+
+    let mut u: undirected::Graph<usize, u8, u32> = undirected::Graph::new();
+    for i in 0..3 {
+        u.insert_node(i, i as u8);
+    }
+    u.insert_edge(0, 1, 7).unwrap();
+    u.insert_edge(1, 2, 8).unwrap();
+    let mut dot = String::new();
+    io::write_dot_undirected(&u, &mut dot).unwrap();
+    assert!(dot.starts_with("graph {") && dot.contains(" -- "));
+    let read = undirected::Graph::from(io::read_dot::<usize, u8, u32, RandomState>(&dot).unwrap());
+    assert_eq!(read.edges_len(), 2);
+    assert_eq!(read.get_weight(2, 1), Ok(&8));
+    let mut graphml = String::new();
+    io::write_graphml_undirected(&u, &mut graphml).unwrap();
+    let read: Graph<usize, u8, u32> = io::read_graphml(&graphml).unwrap();
+    assert_eq!(read.edges_len(), 4);
+    assert_eq!(read.get(&2).unwrap().val(), &2);
+}
+
+#[test]
+fn test_io_read() {
+    let dot = "/* header */
+strict digraph G {
+    rankdir = LR; // left to right
+    node [shape=box];
+    1 [label=10]
+    1 -> 2 -> 3 [color=red, label=5];
+    3:p -> 1
+}";
+    let g: Graph<usize, u32, u32> = io::read_dot(dot).unwrap();
+    assert_eq!(g.get(&1).unwrap().val(), &10);
+    assert_eq!(g.get(&3).unwrap().val(), &0);
+    assert_eq!(g.get_weight(1, 2), Ok(&5));
+    assert_eq!(g.get_weight(2, 3), Ok(&5));
+    assert_eq!(g.get_weight(3, 1), Ok(&0));
+    assert_eq!(g.edges_len(), 3);
+
+    let graphml = r#"<?xml version="1.0"?>
+<graphml>
+  <key id="d0" for="edge" attr.name="weight" attr.type="int"/>
+  <!-- edges may come before their nodes -->
+  <graph edgedefault="undirected">
+    <edge source="1" target="2"><data key="d0">9</data></edge>
+    <edge source="2" target="3" directed="true"/>
+    <node id="1"/>
+  </graph>
+</graphml>"#;
+    let g: Graph<usize, u8, u32> = io::read_graphml(graphml).unwrap();
+    assert_eq!(g.nodes_len(), 3);
+    assert_eq!(g.get_weight(2, 1), Ok(&9));
+    assert_eq!(g.get_weight(2, 3), Ok(&0));
+    assert!(g.get_weight(3, 2).is_err());
+
+    let csv = "# not a comment\n";
+    let g: Graph<String, (), u32> = io::read_edge_list(csv).unwrap();
+    assert!(g.contains(&"# not a comment".to_string()));
+}
+
+#[test]
+fn test_io_errors() {
+    let err = |line, col, kind| ParseErr { line, col, kind };
+    let unexpected = |expected, found: &str| ParseErrKind::Unexpected {
+        expected,
+        found: found.to_string(),
+    };
+
+    assert_eq!(
+        io::read_dot::<usize, u32, u32, RandomState>("digraph {\n  1 -> \n}").unwrap_err(),
+        err(3, 1, unexpected("id", "}"))
+    );
+    assert_eq!(
+        io::read_dot::<usize, u32, u32, RandomState>("digraph { x }").unwrap_err(),
+        err(1, 11, ParseErrKind::InvalidKey("x".to_string()))
+    );
+    assert_eq!(
+        io::read_dot::<usize, u32, u32, RandomState>("graph { 1 -> 2 }").unwrap_err(),
+        err(1, 11, unexpected("--", "->"))
+    );
+    assert_eq!(
+        io::read_dot::<usize, u32, u32, RandomState>("digraph { 1 [label=\"one\"] }").unwrap_err(),
+        err(1, 20, ParseErrKind::InvalidVal("one".to_string()))
+    );
+    assert_eq!(
+        io::read_dot::<usize, u32, u32, RandomState>("digraph {").unwrap_err(),
+        err(1, 10, ParseErrKind::UnexpectedEof)
+    );
+
+    let e = io::read_edge_list::<usize, (), u32, RandomState>("1,2,3\n4,x,1\n").unwrap_err();
+    assert_eq!(e, err(2, 3, ParseErrKind::InvalidKey("x".to_string())));
+    assert_eq!(e.to_string(), "2:3: invalid key \"x\"");
+    assert_eq!(
+        io::read_edge_list::<usize, (), u32, RandomState>("1,2,3,4").unwrap_err(),
+        err(1, 6, unexpected("end of line", ","))
+    );
+    assert_eq!(
+        io::read_edge_list::<usize, (), u32, RandomState>("1,2,-3").unwrap_err(),
+        err(1, 5, ParseErrKind::InvalidWeight("-3".to_string()))
+    );
+    assert_eq!(
+        io::read_edge_list::<String, (), u32, RandomState>("\"a,b").unwrap_err(),
+        err(1, 5, ParseErrKind::UnexpectedEof)
+    );
+    assert_eq!(
+        io::read_adjacency_list::<usize, (), (), RandomState>("0 1\n1  two").unwrap_err(),
+        err(2, 4, ParseErrKind::InvalidKey("two".to_string()))
+    );
+
+    assert_eq!(
+        io::read_graphml::<usize, u32, u32, RandomState>("<graphml>\n<graph></graphml>")
+            .unwrap_err(),
+        err(2, 8, unexpected("matching end tag", "graphml"))
+    );
+    assert_eq!(
+        io::read_graphml::<usize, u32, u32, RandomState>("<graphml><node/></graphml>").unwrap_err(),
+        err(1, 10, unexpected("id attribute", "<node>"))
+    );
+    assert!(io::read_graphml::<usize, u32, u32, RandomState>("<graphml><graph>").is_err());
+    assert!(
+        io::read_graphml::<usize, u32, u32, RandomState>("<graphml/>")
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_generators() {
+    let k5 = generators::complete(5, ());
+    assert_eq!(k5.edges_len(), 10);
+    assert!((0..5).all(|i| k5.degree(&i) == Some(4)));
+    assert_eq!(generators::path(4, ()).edges_len(), 3);
+    assert_eq!(
+        generators::cycle(4, ())
+            .find_hamiltonian_cycle()
+            .unwrap()
+            .len(),
+        4
+    );
+    assert_eq!(generators::cycle(2, ()).edges_len(), 1);
+    let star = generators::star(6, 2u32);
+    assert_eq!(star.degree(&0), Some(5));
+    assert_eq!(star.get_weight(3, 0), Ok(&2));
+
+    let grid = generators::grid(3, 4, ());
+    assert_eq!(grid.nodes_len(), 12);
+    assert_eq!(grid.edges_len(), 3 * 3 + 2 * 4);
+    assert_eq!(grid.degree(&0), Some(2));
+    assert_eq!(grid.degree(&5), Some(4));
+    let k23 = generators::complete_bipartite(2, 3, ());
+    assert_eq!(k23.edges_len(), 6);
+    assert_eq!(k23.as_directed().is_bipartite().unwrap().len(), 5);
+
+    let gnp = |seed| generators::erdos_renyi(30, 0.2, (), &mut StdRng::seed_from_u64(seed));
+    let edges = |g: &undirected::Graph<usize, (), ()>| {
+        let mut e: Vec<(usize, usize)> = g
+            .edges()
+            .iter()
+            .map(|e| (*e.from.min(e.to), *e.from.max(e.to)))
+            .collect();
+        e.sort();
+        e
+    };
+    assert_eq!(edges(&gnp(7)), edges(&gnp(7)));
+    assert_ne!(edges(&gnp(7)), edges(&gnp(8)));
+    let mut rng = StdRng::seed_from_u64(1);
+    assert_eq!(
+        generators::erdos_renyi(10, 1.0, (), &mut rng).edges_len(),
+        45
+    );
+    assert_eq!(
+        generators::erdos_renyi_directed(10, 1.0, (), &mut rng).edges_len(),
+        90
+    );
+    assert_eq!(
+        generators::erdos_renyi_directed(10, 0.0, (), &mut rng).edges_len(),
+        0
+    );
+
+    let ba = generators::barabasi_albert(50, 3, (), &mut rng);
+    assert_eq!(ba.edges_len(), 3 * 47);
+    assert!((3..50).all(|i| ba.degree(&i).unwrap() >= 3));
+    assert!(ba.as_directed().is_connected());
+
+    let dag = generators::random_dag(20, 0.5, (), &mut rng);
+    assert!(dag.edges().iter().all(|e| e.from < e.to));
+    let dag = generators::random_dag_shuffled(20, 0.5, 1u32, &mut rng);
+    assert_eq!(dag.tarjan_scc().len(), 20);
+}
+
+#[test]
+#[should_panic]
+fn test_erdos_renyi_panic() {
+    // checked even when there's no pair of nodes to draw for
+    generators::erdos_renyi(1, 1.5, (), &mut StdRng::seed_from_u64(0));
+}
+
+#[test]
+#[should_panic]
+fn test_random_dag_panic() {
+    generators::random_dag(0, -0.1, (), &mut StdRng::seed_from_u64(0));
+}
+
+#[test]
+fn test_centrality() {
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-6;
+
+    let star = generators::star(5, ()).into_directed();
+    let betweenness = star.betweenness_centrality(false);
+    assert!(close(betweenness[&0], 12.0));
+    assert!(close(betweenness[&3], 0.0));
+    assert!(close(star.betweenness_centrality(true)[&0], 1.0));
+    let path = generators::path(3, ()).into_directed();
+    assert!(close(path.betweenness_centrality(false)[&1], 2.0));
+
+    let closeness = star.closeness_centrality();
+    assert!(close(closeness[&0], 1.0));
+    assert!(close(closeness[&2], 4.0 / 7.0));
+    assert!(close(star.harmonic_centrality()[&1], 2.5));
+    assert!(close(star.degree_centrality()[&0], 2.0));
+    assert!(close(star.in_degree_centrality()[&4], 0.25));
+    assert!(close(star.out_degree_centrality()[&0], 1.0));
+
+    // isolated node reaches nothing
+    let mut g: Graph<char, (), ()> = Graph::new();
+    for key in "abcde".chars() {
+        g.insert_node(key, ());
+    }
+    for (from, to) in [('a', 'b'), ('b', 'c'), ('c', 'a'), ('d', 'a')] {
+        g.insert_edge(from, to, ()).unwrap();
+    }
+    assert!(close(g.closeness_centrality()[&'e'], 0.0));
+    assert!(close(g.harmonic_centrality()[&'e'], 0.0));
+
+    let ranks = g.pagerank(0.85, 1e-10, 100);
+    assert!(close(ranks.values().sum(), 1.0));
+    assert!(ranks[&'a'] > ranks[&'b'] && ranks[&'b'] > ranks[&'d']);
+    assert!(close(ranks[&'d'], ranks[&'e']));
+    let par = g.pagerank_par(0.85, 1e-10, 100);
+    assert!(ranks.iter().all(|(k, r)| close(par[k], *r)));
+
+    let ranks = generators::cycle(5, ())
+        .into_directed()
+        .pagerank(0.85, 1e-10, 100);
+    assert!(ranks.values().all(|r| close(*r, 0.2)));
+    assert!(Graph::<u8, (), ()>::new()
+        .pagerank(0.85, 1e-10, 100)
+        .is_empty());
+}
+
+#[test]
+fn test_visit() {
+    use DfsEvent::*;
+    let mut g: matrix::directed::Graph<4, u8> = matrix::directed::Graph::new();
+    for (from, to) in [(0, 1), (1, 2), (2, 0), (0, 3), (3, 2)] {
+        g.insert_edge(from, to, 1).unwrap();
+    }
+    let mut events = Vec::new();
+    let control = visit::depth_first_search(&g, [0], None, |e| {
+        events.push(e);
+        Control::Continue
+    });
+    assert_eq!(control, Control::Continue);
+    assert_eq!(
+        events,
+        vec![
+            Discover(0, 0),
+            TreeEdge(0, 1),
+            Discover(1, 1),
+            TreeEdge(1, 2),
+            Discover(2, 2),
+            BackEdge(2, 0),
+            Finish(2),
+            Finish(1),
+            TreeEdge(0, 3),
+            Discover(3, 1),
+            CrossEdge(3, 2),
+            Finish(3),
+            Finish(0),
+        ]
+    );
+
+    events.clear();
+    visit::depth_first_search(&g, [0, 2, 1], Some(1), |e| {
+        events.push(e);
+        Control::Continue
+    });
+    assert_eq!(
+        events,
+        vec![
+            Discover(0, 0),
+            TreeEdge(0, 1),
+            Discover(1, 1),
+            Finish(1),
+            TreeEdge(0, 3),
+            Discover(3, 1),
+            Finish(3),
+            Finish(0),
+            Discover(2, 0),
+            CrossEdge(2, 0),
+            Finish(2),
+        ]
+    );
+
+    let mut discovered = Vec::new();
+    let control = visit::depth_first_search(&g, [0], None, |e| match e {
+        Discover(1, _) => Control::Prune,
+        Discover(2, _) => Control::Break,
+        Discover(node, _) => {
+            discovered.push(node);
+            Control::Continue
+        }
+        TreeEdge(_, 3) => Control::Prune,
+        _ => Control::Continue,
+    });
+    assert_eq!(control, Control::Continue);
+    assert_eq!(discovered, vec![0]);
+    let control = visit::depth_first_search(&g, [3], None, |e| match e {
+        Discover(2, _) => Control::Break,
+        _ => Control::Continue,
+    });
+    assert_eq!(control, Control::Break);
+
+    // the edge back to the parent is a back edge in an undirected graph
+    let path = generators::path(3, ());
+    let mut back = 0;
+    visit::depth_first_search(&path, [&0], None, |e| {
+        back += matches!(e, BackEdge(..)) as usize;
+        Control::Continue
+    });
+    assert_eq!(back, 2);
+
+    let grid = generators::grid(3, 3, ());
+    assert_eq!(visit::iddfs(&grid, &0, 10, |n| *n == 8).unwrap().len(), 5);
+    assert_eq!(visit::iddfs(&grid, &0, 3, |n| *n == 8), None);
+    assert_eq!(visit::iddfs(&grid, &4, 0, |n| *n == 4), Some(vec![&4]));
+    // stops before `max_depth` once nothing deeper is left
+    assert_eq!(visit::iddfs(&g, 0, usize::MAX, |n| n == 9), None);
+}
+
+/// edges as sorted `(from, to, weight)` tuples.
+fn sorted_edges<K, T, W>(g: &Graph<K, T, W>) -> Vec<(K, K, W)>
+where
+    K: std::hash::Hash + Ord + Clone,
+    W: Clone,
+{
+    let mut edges: Vec<(K, K, W)> = g
+        .edges()
+        .iter()
+        .map(|e| (e.from.clone(), e.to.clone(), e.weight.clone()))
+        .collect();
+    edges.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+    edges
+}
+
+#[test]
+fn test_transform() {
+    let mut g: Graph<char, u8, u32> = Graph::with_reverse_index();
+    for (i, key) in "abcd".chars().enumerate() {
+        g.insert_node(key, i as u8);
+    }
+    for (from, to, w) in [('a', 'b', 1), ('b', 'c', 2), ('c', 'a', 3), ('c', 'd', 4)] {
+        g.insert_edge(from, to, w).unwrap();
+    }
+
+    let rev = g.reversed();
+    assert!(rev.has_reverse_index());
+    assert_eq!(
+        sorted_edges(&rev),
+        vec![('a', 'c', 3), ('b', 'a', 1), ('c', 'b', 2), ('d', 'c', 4)]
+    );
+    assert_eq!(rev.get(&'d').unwrap().val(), &3);
+    assert_eq!(rev.validate(), Ok(()));
+
+    let sub = g.induced_subgraph(&['a', 'c', 'z']);
+    assert_eq!(sub.nodes_len(), 2);
+    assert_eq!(sorted_edges(&sub), vec![('c', 'a', 3)]);
+
+    let small: Graph<char, String, bool> = g.filter_map(
+        |k, v| (*k != 'd').then(|| v.to_string()),
+        |_, _, w| (*w > 1).then_some(true),
+    );
+    assert_eq!(small.get(&'b').unwrap().val(), "1");
+    assert_eq!(
+        sorted_edges(&small),
+        vec![('b', 'c', true), ('c', 'a', true)]
+    );
+    let doubled = g.map_weights(|_, _, w| *w as f64 * 2.0);
+    assert_eq!(doubled.get_weight('c', 'd'), Ok(&8.0));
+
+    let mut other: Graph<char, u8, u32> = Graph::new();
+    for key in "bcde".chars() {
+        other.insert_node(key, 9);
+    }
+    other.insert_edge('b', 'c', 20).unwrap();
+    other.insert_edge('d', 'e', 5).unwrap();
+    let union = g.union(&other);
+    assert_eq!(union.nodes_len(), 5);
+    assert_eq!(union.get(&'b').unwrap().val(), &1);
+    assert_eq!(union.get(&'e').unwrap().val(), &9);
+    assert_eq!(union.get_weight('b', 'c'), Ok(&2));
+    assert_eq!(union.edges_len(), 5);
+    let inter = g.intersection(&other);
+    assert_eq!(inter.nodes_len(), 3);
+    assert_eq!(sorted_edges(&inter), vec![('b', 'c', 2)]);
+    let diff = g.difference(&other);
+    assert_eq!(diff.nodes_len(), 4);
+    assert_eq!(diff.edges_len(), 3);
+    assert!(diff.get_weight('b', 'c').is_err());
+
+    let comp = g.complement(0);
+    assert_eq!(comp.edges_len(), 4 * 3 - 4);
+    assert!(comp.get_weight('a', 'c').is_ok());
+    assert!(comp.get_weight('a', 'b').is_err());
+    assert_eq!(comp.complement(0).edges_len(), 4);
+
+    // contracting b into a: a -> c comes from b -> c, c -> a stays
+    assert_eq!(g.contract_edge('a', 'b'), Ok(Some(1)));
+    assert_eq!(
+        sorted_edges(&g),
+        vec![('a', 'c', 2), ('c', 'a', 3), ('c', 'd', 4)]
+    );
+    // incoming edge of d moves to c, its self loop is kept
+    g.insert_edge('d', 'd', 7).unwrap();
+    g.insert_edge('a', 'd', 8).unwrap();
+    assert_eq!(g.contract_edge('c', 'd'), Ok(Some(3)));
+    assert_eq!(
+        sorted_edges(&g),
+        vec![('a', 'c', 2), ('c', 'a', 3), ('c', 'c', 7)]
+    );
+    assert_eq!(g.validate(), Ok(()));
+    assert_eq!(g.contract_edge('a', 'z'), Err(EdgeErr::ToNone));
+    assert_eq!(g.contract_edge('a', 'a'), Ok(None));
+}
+
+/// copy of `g` with every key `k` replaced by `f(k)`.
+fn relabel<T: Clone, W: Clone>(
+    g: &Graph<usize, T, W>,
+    f: impl Fn(usize) -> usize,
+) -> Graph<usize, T, W> {
+    let mut ret = Graph::new();
+    for (k, n) in g.iter() {
+        ret.insert_node(f(*k), n.val().clone());
+    }
+    for e in g.edges() {
+        ret.insert_edge(f(*e.from), f(*e.to), e.weight.clone())
+            .unwrap();
+    }
+    ret
+}
+
+#[test]
+fn test_isomorphism() {
+    let grid = generators::grid(3, 4, ()).into_directed();
+    let shuffled = relabel(&grid, |k| (k * 5 + 3) % 12);
+    assert!(hash_map::is_isomorphic(&grid, &shuffled));
+    let cycle = generators::cycle(12, ()).into_directed();
+    assert!(!hash_map::is_isomorphic(&grid, &cycle));
+
+    // same degrees, one cycle against two triangles
+    let hexagon = generators::cycle(6, ()).into_directed();
+    let mut triangles = generators::cycle(3, ()).into_directed();
+    triangles = triangles.union(&relabel(&triangles, |k| k + 3));
+    assert!(!hash_map::is_isomorphic(&hexagon, &triangles));
+
+    // a directed cycle is isomorphic to its reverse unless values tell nodes apart
+    let mut ring: Graph<usize, char, u32> = Graph::new();
+    for (i, c) in "abc".chars().enumerate() {
+        ring.insert_node(i, c);
+    }
+    for i in 0..3 {
+        ring.insert_edge(i, (i + 1) % 3, i as u32).unwrap();
+    }
+    let rev = ring.reversed();
+    assert!(hash_map::is_isomorphic(&ring, &rev));
+    assert!(hash_map::is_isomorphic_matching(
+        &ring,
+        &relabel(&ring, |k| (k + 1) % 3),
+        |a, b| a == b,
+        |a, b| a == b
+    ));
+    assert!(!hash_map::is_isomorphic_matching(
+        &ring,
+        &rev,
+        |a, b| a == b,
+        |_, _| true
+    ));
+    // weights moved one edge along the cycle
+    let shifted = ring.map_weights(|_, _, w| (w + 1) % 3);
+    assert!(hash_map::is_isomorphic_matching(
+        &ring,
+        &shifted,
+        |_, _| true,
+        |a, b| a == b
+    ));
+    assert!(!hash_map::is_isomorphic_matching(
+        &ring,
+        &shifted,
+        |a, b| a == b,
+        |a, b| a == b
+    ));
+
+    let triangle = generators::complete(3, ()).into_directed();
+    let k4 = generators::complete(4, ()).into_directed();
+    assert_eq!(hash_map::subgraph_isomorphisms(&triangle, &k4).count(), 24);
+    let path = generators::path(3, ()).into_directed();
+    let square = generators::cycle(4, ()).into_directed();
+    assert_eq!(hash_map::subgraph_isomorphisms(&path, &square).count(), 8);
+    assert_eq!(hash_map::subgraph_isomorphisms(&path, &k4).count(), 0);
+    for mapping in hash_map::subgraph_isomorphisms(&path, &square) {
+        assert!(square.get_weight(*mapping[&0], *mapping[&1]).is_ok());
+        assert!(square.get_weight(*mapping[&0], *mapping[&2]).is_err());
+    }
+    assert_eq!(hash_map::subgraph_isomorphisms(&k4, &triangle).count(), 0);
+    assert_eq!(
+        hash_map::subgraph_isomorphisms(&Graph::<u8, (), ()>::new(), &k4).count(),
+        1
+    );
+
+    let mut edge: Graph<char, (), u32> = Graph::new();
+    edge.insert_node('x', ());
+    edge.insert_node('y', ());
+    edge.insert_edge('x', 'y', 1).unwrap();
+    assert_eq!(hash_map::subgraph_isomorphisms(&edge, &ring).count(), 3);
+    let mapped: Vec<_> =
+        hash_map::subgraph_isomorphisms_matching(&edge, &ring, |_, _| true, |a, b| a == b)
+            .collect();
+    assert_eq!(mapped.len(), 1);
+    assert_eq!((mapped[0][&'x'], mapped[0][&'y']), (&1, &2));
+}
+
+fn petersen() -> undirected::Graph<usize, (), ()> {
+    let mut g = generators::cycle(5, ());
+    for i in 0..5 {
+        g.insert_node(i + 5, ());
+        g.insert_edge(i, i + 5, ()).unwrap();
+    }
+    for i in 0..5 {
+        g.insert_edge(i + 5, (i + 2) % 5 + 5, ()).unwrap();
+    }
+    g
+}
+
+#[test]
+fn test_coloring() {
+    let proper = |g: &undirected::Graph<usize, (), ()>, colors: &HashMap<&usize, usize>| {
+        g.as_directed()
+            .edges()
+            .iter()
+            .all(|e| colors[e.from] != colors[e.to])
+    };
+    let p = petersen();
+    for strategy in [Strategy::Natural, Strategy::LargestFirst, Strategy::DSatur] {
+        let colors = coloring::greedy_coloring(&p, strategy);
+        assert_eq!(colors.len(), 10);
+        assert!(proper(&p, &colors));
+    }
+    let colors = coloring::optimal_coloring(&p);
+    assert!(proper(&p, &colors));
+    assert_eq!(colors.values().max(), Some(&2));
+    assert_eq!(coloring::chromatic_number(&p), 3);
+    assert_eq!(coloring::chromatic_number(&generators::cycle(7, ())), 3);
+    assert_eq!(coloring::chromatic_number(&generators::grid(3, 3, ())), 2);
+    assert_eq!(coloring::chromatic_number(&generators::complete(5, ())), 5);
+    assert_eq!(
+        coloring::chromatic_number(&undirected::Graph::<u8, (), ()>::new()),
+        0
+    );
+
+    // crown graph, `2i` and `2j + 1` connected for i != j. in natural order every
+    // pair takes a new color, DSatur sees it's bipartite
+    let mut crown: matrix::undirected::Graph<8, ()> = matrix::undirected::Graph::new();
+    for i in 0..4 {
+        for j in (0..4).filter(|&j| j != i) {
+            crown.insert_edge_unwrap(2 * i, 2 * j + 1, ());
+        }
+    }
+    let colors_len = |strategy| {
+        let colors = coloring::greedy_coloring(&crown, strategy);
+        colors.values().max().unwrap() + 1
+    };
+    assert_eq!(colors_len(Strategy::Natural), 4);
+    assert_eq!(colors_len(Strategy::DSatur), 2);
+    assert_eq!(coloring::chromatic_number(&crown), 2);
+}
+
+#[test]
+fn test_clique() {
+    let mut g: undirected::Graph<u8, (), ()> = undirected::Graph::new();
+    for i in 0..5 {
+        g.insert_node(i, ());
+    }
+    for (a, b) in [(0, 1), (1, 2), (2, 0), (2, 3)] {
+        g.insert_edge(a, b, ()).unwrap();
+    }
+    let mut cliques: Vec<Vec<u8>> = clique::maximal_cliques(&g)
+        .into_iter()
+        .map(|c| {
+            let mut c: Vec<u8> = c.into_iter().copied().collect();
+            c.sort();
+            c
+        })
+        .collect();
+    cliques.sort();
+    assert_eq!(cliques, vec![vec![0, 1, 2], vec![2, 3], vec![4]]);
+    let mut max = clique::maximum_clique(&g);
+    max.sort();
+    assert_eq!(max, vec![&0, &1, &2]);
+    assert_eq!(clique::maximum_independent_set(&g).len(), 3);
+
+    let p = petersen();
+    assert_eq!(clique::maximal_cliques(&p).len(), 15);
+    assert_eq!(clique::maximum_clique(&p).len(), 2);
+    let independent = clique::maximum_independent_set(&p);
+    assert_eq!(independent.len(), 4);
+    for a in independent.iter() {
+        assert!(independent.iter().all(|b| p.get_weight(**a, **b).is_err()));
+    }
+    assert_eq!(
+        clique::maximum_clique(&generators::complete(6, ())).len(),
+        6
+    );
+    assert!(clique::maximal_cliques(&undirected::Graph::<u8, (), ()>::new()).is_empty());
+
+    let mut m: matrix::undirected::Graph<4, u8> = matrix::undirected::Graph::new();
+    m.insert_edge_unwrap(0, 1, 1);
+    m.insert_edge_unwrap(2, 3, 1);
+    m.insert_edge_unwrap(3, 3, 1);
+    assert_eq!(clique::maximal_cliques(&m), vec![vec![0, 1], vec![2, 3]]);
+    assert_eq!(clique::maximum_independent_set(&m).len(), 2);
+}
+
+#[test]
+fn test_parallel() {
+    let mut rng = StdRng::seed_from_u64(7);
+    // sparse enough to fall apart into several components
+    let g = generators::erdos_renyi_directed(300, 0.004, (), &mut rng)
+        .map_weights(|from, to, _| (from * 31 + to * 17) % 10);
+    let components = g.connected_components();
+    assert!(components.len() > 1);
+    assert_eq!(g.connected_components_par(), components);
+    assert_eq!(
+        components.iter().map(|c| c.len()).sum::<usize>(),
+        g.nodes_len()
+    );
+
+    for start in [0, 1, 150, 299] {
+        let levels = g.bfs_levels(&start);
+        assert_eq!(g.bfs_levels_par(&start), levels);
+        assert_eq!(levels[&start], 0);
+        for (key, level) in levels.iter() {
+            assert!(g
+                .get(key)
+                .unwrap()
+                .neighbors()
+                .keys()
+                .all(|n| levels[n] <= level + 1));
+        }
+    }
+    assert!(g.bfs_levels_par(&300).is_empty());
+
+    let dist = g.all_pairs_shortest_dist();
+    assert_eq!(g.all_pairs_shortest_dist_par(), dist);
+    assert_eq!(dist.len(), g.nodes_len());
+    assert_eq!(dist[&0][&0], 0);
+    let single: HashMap<&usize, usize> = g
+        .dijkstra_shortest_dist(&5)
+        .into_iter()
+        .filter(|(_, d)| *d != usize::MAX)
+        .collect();
+    assert_eq!(dist[&5], single);
+
+    let empty: Graph<u8, (), u8> = Graph::new();
+    assert!(empty.connected_components_par().is_empty());
+    assert!(empty.all_pairs_shortest_dist_par().is_empty());
+}
+
+fn ordered_sample() -> Graph<u32, char, u32> {
+    let mut g = Graph::with_insertion_order();
+    for (key, val) in [(5, 'e'), (3, 'c'), (9, 'i'), (1, 'a')] {
+        g.insert_node(key, val);
+    }
+    for (from, to, weight) in [(5, 9, 1), (5, 3, 2), (3, 1, 3), (9, 1, 4), (1, 5, 5)] {
+        g.insert_edge(from, to, weight).unwrap();
+    }
+    g
+}
+
+#[test]
+fn test_insertion_order() {
+    let mut g = ordered_sample();
+    assert!(g.has_insertion_order());
+    let keys = |g: &Graph<u32, char, u32>| g.iter().map(|(k, _)| *k).collect::<Vec<u32>>();
+    let edges = |g: &Graph<u32, char, u32>| {
+        g.edges()
+            .iter()
+            .map(|e| (*e.from, *e.to))
+            .collect::<Vec<(u32, u32)>>()
+    };
+    assert_eq!(keys(&g), vec![5, 3, 9, 1]);
+    assert_eq!(edges(&g), vec![(5, 9), (5, 3), (3, 1), (9, 1), (1, 5)]);
+    let bfs: Vec<u32> = g.bfs_iter(&5).map(|n| *n.key()).collect();
+    assert_eq!(bfs, vec![5, 9, 3, 1]);
+    let dfs: Vec<u32> = g.dfs_iter(&5).map(|n| *n.key()).collect();
+    assert_eq!(dfs, vec![5, 3, 1, 9]);
+    let neibs: Vec<(&u32, &u32)> = g.out_neighbors(&5).unwrap().collect();
+    assert_eq!(neibs, vec![(&9, &1), (&3, &2)]);
+    assert_eq!(
+        format!("{}", g),
+        format!("{}", ordered_sample()),
+        "Display is the same for graphs built the same way"
+    );
+    assert_eq!(
+        g.find_eulerian_path(),
+        ordered_sample().find_eulerian_path()
+    );
+    assert_eq!(
+        g.reversed().iter().map(|(k, _)| *k).collect::<Vec<u32>>(),
+        vec![5, 3, 9, 1]
+    );
+
+    // replacing keeps the position, removing and inserting again moves to the end
+    g.insert_edge(5, 9, 10).unwrap();
+    g.insert_node(3, 'C');
+    g.insert_edge(3, 1, 3).unwrap();
+    assert_eq!(keys(&g), vec![5, 3, 9, 1]);
+    assert_eq!(edges(&g), vec![(5, 9), (5, 3), (3, 1), (9, 1), (1, 5)]);
+    g.remove_edge(5, 9).unwrap();
+    g.insert_edge(5, 9, 1).unwrap();
+    g.remove(3);
+    g.insert_node(3, 'c');
+    g.insert_edge(5, 3, 2).unwrap();
+    assert_eq!(keys(&g), vec![5, 9, 1, 3]);
+    assert_eq!(edges(&g), vec![(5, 9), (5, 3), (9, 1), (1, 5)]);
+    g.remove_edge(1, 5).unwrap();
+    g.insert_edge(1, 9, 6).unwrap();
+    assert_eq!(g.out_neighbors(&1).unwrap().count(), 1);
+    assert!(g.validate().is_ok());
+
+    for (_, node) in g.iter_mut() {
+        *node.val_mut() = node.val().to_ascii_uppercase();
+    }
+    let vals: Vec<char> = g.into_iter().map(|(_, n)| *n.val()).collect();
+    assert_eq!(vals, vec!['E', 'I', 'A', 'C']);
+
+    let mut h: Graph<u32, (), ()> = Graph::new();
+    h.insert_node(2, ());
+    h.insert_node(1, ());
+    h.enable_insertion_order();
+    h.insert_node(0, ());
+    assert_eq!(h.iter().last().map(|(k, _)| *k), Some(0));
+    h.disable_insertion_order();
+    assert!(!h.has_insertion_order());
+    let mut keys: Vec<u32> = h.into_iter().map(|(k, _)| k).collect();
+    keys.sort();
+    assert_eq!(keys, vec![0, 1, 2]);
+
+    let mut u: undirected::Graph<u32, (), u32> = undirected::Graph::with_insertion_order();
+    for key in [4, 2, 7] {
+        u.insert_node(key, ());
+    }
+    u.insert_edge(7, 2, 1).unwrap();
+    u.insert_edge(4, 7, 2).unwrap();
+    assert!(u.has_insertion_order());
+    let keys: Vec<u32> = u.iter().map(|(k, _)| *k).collect();
+    assert_eq!(keys, vec![4, 2, 7]);
+    let edges: Vec<(u32, u32)> = u.edges().iter().map(|e| (*e.from, *e.to)).collect();
+    assert_eq!(edges, vec![(4, 7), (2, 7)]);
+}
+
+#[test]
+fn test_custom_hasher() {
+    type Fixed = BuildHasherDefault<DefaultHasher>;
+    let build = || {
+        let mut g: Graph<u32, (), u32, Fixed> = Graph::default();
+        for i in 0..50 {
+            g.insert_node(i, ());
+        }
+        for i in 0..50 {
+            g.insert_edge(i, (i * 7 + 3) % 50, i).unwrap();
+            g.insert_edge(i, (i + 1) % 50, 1).unwrap();
+        }
+        g
+    };
+    let (a, b) = (build(), build());
+    let keys = |g: &Graph<u32, (), u32, Fixed>| g.iter().map(|(k, _)| *k).collect::<Vec<u32>>();
+    assert_eq!(keys(&a), keys(&b));
+    assert_eq!(a.edges(), b.edges());
+    assert_eq!(format!("{}", a), format!("{}", b));
+    let dfs =
+        |g: &Graph<u32, (), u32, Fixed>| g.dfs_iter(&0).map(|n| *n.key()).collect::<Vec<u32>>();
+    assert_eq!(dfs(&a), dfs(&b));
+    assert_eq!(a.tarjan_scc().len(), 1);
+    assert_eq!(algo::dijkstra(&a, &0).len(), 50);
+    let mut c = build();
+    c.insert_node(50, ());
+    assert_eq!(
+        c.get(&50),
+        Some(&Node::with_hasher(50, (), Fixed::default()))
+    );
+
+    let (x, y): (undirected::Graph<u32, (), u32, Fixed>, _) =
+        (build().into(), undirected::Graph::from(build()));
+    assert_eq!(x.edges(), y.edges());
+    let (csr, csr_keys) = CsrGraph::from_graph(&a);
+    assert_eq!((csr.edges_len(), csr_keys), (a.edges_len(), keys(&a)));
+    let mut list = String::new();
+    io::write_edge_list(&a, &mut list).unwrap();
+    let read: Graph<u32, (), u32, Fixed> = io::read_edge_list(&list).unwrap();
+    assert_eq!(read.edges_len(), a.edges_len());
+
+    let mut m: multi::Graph<u32, (), u32, Fixed> = multi::Graph::default();
+    m.insert_node(0, ());
+    m.insert_node(1, ());
+    m.insert_edge(0, 1, 4).unwrap();
+    m.insert_edge(0, 1, 2).unwrap();
+    let simple: Graph<u32, (), u32, Fixed> = m.to_simple();
+    assert_eq!(simple.get_weight(0, 1), Ok(&2));
+}
+
+#[test]
+fn test_ordered_algorithms() {
+    // every graph gets its own random hasher, only the insertion order is shared
+    let build = || {
+        let mut g: Graph<u32, (), u32> = Graph::with_insertion_order();
+        for i in 0..30 {
+            g.insert_node(i * 7 % 30, ());
+        }
+        for i in 0..30 {
+            g.insert_edge(i, (i * 11 + 5) % 30, i % 4).unwrap();
+            g.insert_edge(i, (i + 3) % 30, 2).unwrap();
+        }
+        // a path hanging off the rest for bridges and articulation points
+        for key in [40, 41, 42] {
+            g.insert_node(key, ());
+        }
+        for (from, to) in [(40, 20), (41, 40), (40, 42)] {
+            g.insert_edge(from, to, 1).unwrap();
+        }
+        g
+    };
+    let runs = |g: &Graph<u32, (), u32>| {
+        let edges = |edges: Vec<Edge<u32, u32>>| {
+            edges
+                .iter()
+                .map(|e| (*e.from, *e.to, *e.weight))
+                .collect::<Vec<(u32, u32, u32)>>()
+        };
+        let undirected = undirected::Graph::from(g.map_weights(|_, _, w| *w));
+        let distances = g.map_weights(|_, _, w| *w as usize);
+        let half: Vec<u32> = (0..15).collect();
+        let subgraph = g.induced_subgraph(&half);
+        let dominators = g.dominators(&0).unwrap();
+        let frontiers: Vec<Option<&[&u32]>> = g
+            .iter()
+            .map(|(k, _)| dominators.dominance_frontier(k))
+            .collect();
+        format!(
+            "{:?}",
+            (
+                g.tarjan_scc(),
+                g.kosaraju_scc(),
+                edges(g.minimum_spanning_forest().0),
+                edges(g.minimum_spanning_forest_prim().0),
+                edges(undirected.edges()),
+                g.bridges(),
+                g.articulation_points(),
+                g.biconnected_components(),
+                g.maximum_bipartite_matching(),
+                distances.dijkstra_shortest_dist(&0),
+                edges(subgraph.edges()),
+                frontiers,
+            )
+        )
+    };
+    let first = runs(&build());
+    for _ in 0..10 {
+        assert_eq!(runs(&build()), first);
+    }
+}
+
+#[test]
+fn test_transitive() {
+    let mut dag: Graph<u32, (), u32> = Graph::new();
+    for i in 1..=5 {
+        dag.insert_node(i, ());
+    }
+    for (from, to) in [
+        (1, 2),
+        (2, 3),
+        (1, 3),
+        (3, 4),
+        (1, 4),
+        (2, 4),
+        (1, 5),
+        (5, 4),
+    ] {
+        dag.insert_edge(from, to, from * 10 + to).unwrap();
+    }
+    let reduction = dag.transitive_reduction().unwrap();
+    assert_eq!(reduction.nodes_len(), 5);
+    assert_eq!(reduction.edges_len(), 5);
+    assert_eq!(reduction.get_weight(2, 3), Ok(&23));
+    assert_eq!(reduction.get_weight(1, 3), Err(EdgeErr::ToNone));
+    assert_eq!(reduction.get_weight(2, 4), Err(EdgeErr::ToNone));
+    let closure = dag.transitive_closure(0);
+    assert_eq!(closure.edges_len(), 8);
+    assert_eq!(closure.get_weight(1, 4), Ok(&14));
+    assert_eq!(closure.get_weight(2, 4), Ok(&24));
+    assert_eq!(closure.get_weight(5, 4), Ok(&54));
+    assert_eq!(closure.get_weight(1, 1), Err(EdgeErr::ToNone));
+    let sorted_edges = |g: &Graph<u32, (), u32>| {
+        let mut edges: Vec<(u32, u32, u32)> = g
+            .edges()
+            .iter()
+            .map(|e| (*e.from, *e.to, *e.weight))
+            .collect();
+        edges.sort();
+        edges
+    };
+    assert_eq!(
+        sorted_edges(&closure.transitive_reduction().unwrap()),
+        sorted_edges(&reduction)
+    );
+
+    let reach = dag.reachability();
+    assert!(reach.reaches(&1, &4));
+    assert!(reach.reaches(&3, &3));
+    assert!(!reach.reaches(&4, &1));
+    assert!(!reach.reaches(&2, &5));
+    assert!(!reach.reaches(&1, &6));
+    let mut from_two = reach.reachable_from(&2).unwrap();
+    from_two.sort();
+    assert_eq!(from_two, vec![&2, &3, &4]);
+    assert_eq!(reach.reachable_from(&6), None);
+
+    let g = scc_graph();
+    assert!(g.transitive_reduction().is_none());
+    let closure = g.transitive_closure(0);
+    assert_eq!(closure.edges_len(), 24);
+    assert_eq!(closure.get_weight('a', 'a'), Ok(&0));
+    assert_eq!(closure.get_weight('c', 'd'), Ok(&4));
+    assert!(closure.get_weight('f', 'f').is_err());
+    let reach = g.reachability();
+    assert!(reach.reaches(&'c', &'b'));
+    assert!(reach.reaches(&'a', &'f'));
+    assert!(!reach.reaches(&'f', &'d'));
+    assert!(!reach.reaches(&'g', &'a'));
+
+    let mut looped = dag.map_weights(|_, _, w| *w);
+    looped.insert_edge(5, 5, 0).unwrap();
+    assert!(looped.transitive_reduction().is_none());
+    assert_eq!(looped.transitive_closure(0).get_weight(5, 5), Ok(&0));
+
+    let mut rng = StdRng::seed_from_u64(3);
+    let random = generators::random_dag_shuffled(150, 0.03, (), &mut rng);
+    let reach = random.reachability();
+    for from in 0..150 {
+        let levels = random.bfs_levels(&from);
+        for to in 0..150 {
+            assert_eq!(reach.reaches(&from, &to), levels.contains_key(&to));
+        }
+    }
+    let reduction = random.transitive_reduction().unwrap();
+    let reduced = reduction.reachability();
+    assert!((0..150).all(|from| {
+        let mut a = reach.reachable_from(&from).unwrap();
+        let mut b = reduced.reachable_from(&from).unwrap();
+        a.sort();
+        b.sort();
+        a == b
+    }));
+    assert!(reduction.edges_len() <= random.edges_len());
+}
+
+/// dominators of every reachable node, `a` dominates `b` if `b` is unreachable from
+/// `entry` once `a` is removed.
+fn brute_dominators(g: &Graph<usize, (), ()>, entry: usize) -> HashMap<usize, Vec<usize>> {
+    let reachable = g.bfs_levels(&entry);
+    let mut dominators: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &a in reachable.keys() {
+        let without = g.induced_subgraph(reachable.keys().copied().filter(|k| *k != a));
+        let levels = without.bfs_levels(&entry);
+        for &b in reachable.keys() {
+            if a == b || !levels.contains_key(b) {
+                dominators.entry(*b).or_default().push(*a);
+            }
+        }
+    }
+    dominators
+}
+
+#[test]
+fn test_dominators() {
+    let mut g: Graph<u32, (), ()> = Graph::new();
+    for i in 1..=8 {
+        g.insert_node(i, ());
+    }
+    for (from, to) in [
+        (1, 2),
+        (2, 3),
+        (2, 4),
+        (3, 5),
+        (4, 5),
+        (5, 6),
+        (6, 2),
+        (6, 7),
+        (8, 5),
+    ] {
+        g.insert_edge(from, to, ()).unwrap();
+    }
+    assert!(g.dominators(&9).is_none());
+    let dom = g.dominators(&1).unwrap();
+    assert_eq!(dom.root(), &1);
+    let idoms: Vec<Option<&u32>> = (1..=8).map(|k| dom.immediate_dominator(&k)).collect();
+    assert_eq!(
+        idoms,
+        vec![
+            None,
+            Some(&1),
+            Some(&2),
+            Some(&2),
+            Some(&2),
+            Some(&5),
+            Some(&6),
+            None
+        ]
+    );
+    assert_eq!(dom.dominators(&7), Some(vec![&7, &6, &5, &2, &1]));
+    assert_eq!(dom.dominators(&8), None);
+    assert!(dom.dominates(&2, &6));
+    assert!(dom.dominates(&6, &6));
+    assert!(!dom.dominates(&3, &5));
+    assert!(!dom.dominates(&8, &5));
+    let frontier = |k: u32| {
+        let mut f: Vec<u32> = dom
+            .dominance_frontier(&k)
+            .unwrap()
+            .iter()
+            .map(|k| **k)
+            .collect();
+        f.sort();
+        f
+    };
+    assert_eq!(frontier(1), vec![]);
+    assert_eq!(frontier(2), vec![2]);
+    assert_eq!(frontier(3), vec![5]);
+    assert_eq!(frontier(4), vec![5]);
+    assert_eq!(frontier(5), vec![2]);
+    assert_eq!(frontier(6), vec![2]);
+    assert_eq!(frontier(7), vec![]);
+    assert_eq!(dom.dominance_frontier(&8), None);
+
+    let lca = dom.lca_index();
+    assert_eq!(lca.nodes_len(), 7);
+    assert_eq!(lca.lca(&&3, &&7), Some(&&2));
+    assert_eq!(lca.lca(&&7, &&6), Some(&&6));
+    assert_eq!(lca.depth(&&7), Some(4));
+    assert_eq!(lca.distance(&&3, &&7), Some(4));
+    assert_eq!(lca.ancestor(&&7, 2), Some(&&5));
+    assert_eq!(lca.ancestor(&&7, 5), None);
+    assert!(lca.is_ancestor(&&5, &&7));
+    assert!(!lca.is_ancestor(&&3, &&5));
+    assert!(lca.lca(&&8, &&1).is_none());
+
+    let mut looped: Graph<u32, (), ()> = Graph::new();
+    looped.insert_node(1, ());
+    looped.insert_node(2, ());
+    looped.insert_edge(1, 2, ()).unwrap();
+    looped.insert_edge(2, 1, ()).unwrap();
+    let dom = looped.dominators(&1).unwrap();
+    assert_eq!(dom.dominance_frontier(&1), Some(&[&1][..]));
+    assert_eq!(dom.dominance_frontier(&2), Some(&[&1][..]));
+
+    for seed in 0..5 {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let g = generators::erdos_renyi_directed(40, 0.06, (), &mut rng);
+        let brute = brute_dominators(&g, 0);
+        let dom = g.dominators(&0).unwrap();
+        let lca = dom.lca_index();
+        for (b, dominators) in brute.iter() {
+            let mut chain: Vec<usize> = dom.dominators(b).unwrap().into_iter().copied().collect();
+            chain.sort();
+            let mut expected = dominators.clone();
+            expected.sort();
+            assert_eq!(chain, expected);
+            for a in brute.keys() {
+                assert_eq!(lca.is_ancestor(&a, &b), dominators.contains(a));
+            }
+            // y is in the frontier of x if x dominates a predecessor of y but not y strictly
+            let mut frontier: Vec<usize> = dom
+                .dominance_frontier(b)
+                .unwrap()
+                .iter()
+                .map(|k| **k)
+                .collect();
+            frontier.sort();
+            let mut expected: Vec<usize> = brute
+                .keys()
+                .copied()
+                .filter(|y| {
+                    let strict = y != b && brute[y].contains(b);
+                    !strict
+                        && g.in_neighbors(y)
+                            .unwrap()
+                            .into_iter()
+                            .any(|p| brute.get(p).is_some_and(|d| d.contains(b)))
+                })
+                .collect();
+            expected.sort();
+            assert_eq!(frontier, expected);
+        }
+        assert_eq!(lca.nodes_len(), brute.len());
+    }
+}
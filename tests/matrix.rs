@@ -34,6 +34,24 @@ fn test_matrix_vec_mult() {
     assert_eq!(m5, mult_expected);
 }
 
+#[test]
+fn test_matrix_vec_get() {
+    let mut m = ds_rs::matrix::vec::Matrix::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    assert_eq!(m.get(0, 2), Some(&3));
+    assert_eq!(m.get(1, 0), Some(&4));
+    assert_eq!(m.get(1, 2), Some(&6));
+    assert_eq!(m.get(0, 3), None);
+    assert_eq!(m.get(2, 0), None);
+    *m.get_mut(1, 1).unwrap() = 50;
+    assert_eq!(m.get_mut(0, 3), None);
+    assert_eq!(unsafe { *m.get_unchecked(1, 1) }, 50);
+    unsafe { *m.get_unchecked_mut(1, 2) = 60 };
+    assert_eq!(
+        m.transpose(),
+        ds_rs::matrix::vec::Matrix::new(vec![vec![1, 4], vec![2, 50], vec![3, 60]])
+    );
+}
+
 #[test]
 fn test_matrix_add() {
     let m1 = ds_rs::matrix::array::Matrix::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
@@ -46,3 +64,53 @@ fn test_matrix_add() {
     assert_eq!(m3, add_expected);
     assert_eq!(m4, add_expected);
 }
+
+#[test]
+fn test_matrix_vec_grow_shrink() {
+    let mut m = ds_rs::matrix::vec::Matrix::from([[1, 2], [3, 4]]);
+    m.push_row_col(0);
+    assert_eq!(
+        m,
+        ds_rs::matrix::vec::Matrix::from([[1, 2, 0], [3, 4, 0], [0, 0, 0]])
+    );
+    m.remove_row_col(0);
+    assert_eq!(m, ds_rs::matrix::vec::Matrix::from([[4, 0], [0, 0]]));
+
+    let mut e = ds_rs::matrix::vec::Matrix::from_elem(0, 0, 7);
+    e.push_row_col(7);
+    assert_eq!(e.rows_len(), 1);
+    assert_eq!(e.get(0, 0), Some(&7));
+}
+
+#[test]
+fn test_bit_matrix() {
+    let mut m = ds_rs::matrix::bit::BitMatrix::new(3, 70);
+    assert_eq!(m.set(0, 1, true), Some(false));
+    assert_eq!(m.set(0, 65, true), Some(false));
+    assert_eq!(m.set(2, 69, true), Some(false));
+    assert_eq!(m.set(3, 0, true), None);
+    assert_eq!(m.get(0, 65), Some(true));
+    assert_eq!(m.get(0, 70), None);
+    assert_eq!(m.row_ones(0).collect::<Vec<_>>(), vec![1, 65]);
+    assert_eq!(m.count_ones(), 3);
+
+    m.remove_col(1);
+    assert_eq!(m.row_ones(0).collect::<Vec<_>>(), vec![64]);
+    assert_eq!(m.row_ones(2).collect::<Vec<_>>(), vec![68]);
+    m.remove_row(0);
+    assert_eq!(m.rows_len(), 2);
+    assert_eq!(m.row_ones(1).collect::<Vec<_>>(), vec![68]);
+
+    let mut g = ds_rs::matrix::bit::BitMatrix::new(1, 63);
+    g.set(0, 62, true);
+    g.push_col();
+    g.push_col();
+    g.set(0, 64, true);
+    g.push_row();
+    assert_eq!(g.row_ones(0).collect::<Vec<_>>(), vec![62, 64]);
+    assert_eq!(g.row_count_ones(1), 0);
+    assert_eq!(g.row_ones(5).count(), 0);
+    g.set(1, 3, true);
+    g.or_row(1, 0);
+    assert_eq!(g.row_ones(1).collect::<Vec<_>>(), vec![3, 62, 64]);
+}
@@ -374,3 +374,43 @@ fn test_basic_rawptr() {
 
     assert!(b.is_empty());
 }
+
+#[test]
+fn test_lca_rawptr() {
+    let mut b: ds_rs::tree::rawptr::BinaryTree<u32> = ds_rs::tree::rawptr::BinaryTree::new();
+    assert!(b.lca_index().is_none());
+    {
+        let r = b.set_root_mut(1);
+        let l = r.set_left_mut(2);
+        l.set_left(4);
+        l.set_right_mut(5).set_left(7);
+        r.set_right_mut(3).set_right(6);
+    }
+    let mut other = ds_rs::tree::rawptr::BinaryTree::new();
+    other.set_root(1);
+
+    let lca = b.lca_index().unwrap();
+    let r = b.root().unwrap();
+    let (n2, n3) = (r.left().unwrap(), r.right().unwrap());
+    let (n4, n5, n6) = (n2.left().unwrap(), n2.right().unwrap(), n3.right().unwrap());
+    let n7 = n5.left().unwrap();
+
+    assert_eq!(lca.lca(n7, n4).unwrap().val(), &2);
+    assert_eq!(lca.lca(n7, n6).unwrap().val(), &1);
+    assert_eq!(lca.lca(n5, n7).unwrap().val(), &5);
+    assert_eq!(lca.depth(n7), Some(3));
+    assert_eq!(lca.distance(n4, n7), Some(3));
+    assert_eq!(lca.distance(n6, n6), Some(0));
+    assert!(lca.is_ancestor(n2, n7));
+    assert!(!lca.is_ancestor(n3, n7));
+    assert!(lca.lca(n2, other.root().unwrap()).is_none());
+
+    let index = ds_rs::tree::lca::LcaIndex::new(1, |&n| (2 * n..=2 * n + 1).filter(|c| *c < 100));
+    assert_eq!(index.nodes_len(), 99);
+    assert_eq!(index.root(), &1);
+    assert_eq!(index.lca(&96, &99), Some(&24));
+    assert_eq!(index.lca(&64, &65), Some(&32));
+    assert_eq!(index.depth(&64), Some(6));
+    assert_eq!(index.ancestor(&99, 3), Some(&12));
+    assert_eq!(index.lca(&0, &1), None);
+}